@@ -0,0 +1,304 @@
+use crate::memory::{self, Usage};
+use crate::parse::ParseError;
+use crate::puzzle::PuzzleInfo;
+use crate::runner::{self, PartFailure, SolveError};
+use crate::solution::{DynSolution, Part};
+use std::hint::black_box;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// What each stage allocated, counted in a run of its own so that counting
+/// does not slow down the timed runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryUsage {
+    pub parse: Usage,
+    pub parts: Vec<(Part, Usage)>,
+}
+
+pub struct DayBench {
+    pub puzzle: &'static PuzzleInfo,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+    pub memory: Option<MemoryUsage>,
+    /// The parts that panicked or ran out of time, and were not timed.
+    pub failures: Vec<PartFailure>,
+}
+
+/// Parses `input` and solves each of `parts` `iterations` times, timing each
+/// stage separately, and also counts what each stage allocates if
+/// `count_memory`.
+pub fn run(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+    count_memory: bool,
+) -> Result<DayBench, ParseError> {
+    let memory = if count_memory {
+        Some(measure_memory(solution, input, parts)?)
+    } else {
+        None
+    };
+    let iterations = iterations.max(1);
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part_samples = vec![Vec::with_capacity(iterations); parts.len()];
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = solution.parse(black_box(input))?;
+        parse.push(start.elapsed());
+
+        for (&part, samples) in parts.iter().zip(part_samples.iter_mut()) {
+            let start = Instant::now();
+            black_box(solution.solve(part, &*parsed));
+            samples.push(start.elapsed());
+        }
+    }
+    Ok(DayBench {
+        puzzle: solution.info(),
+        parse: Stats::new(parse),
+        parts: parts
+            .iter()
+            .copied()
+            .zip(part_samples.into_iter().map(Stats::new))
+            .collect(),
+        memory,
+        failures: Vec::new(),
+    })
+}
+
+/// Like [`run`], but first solves each part once with
+/// [`runner::solve_with_timeout`], and then only times the parts that
+/// answered. The timed runs share one budget of `timeout` for each iteration,
+/// plus one more for counting memory, so a slow iteration can use up time
+/// that a quicker one left over.
+pub fn run_with_timeout(
+    solution: &'static dyn DynSolution,
+    input: String,
+    parts: &[Part],
+    iterations: usize,
+    count_memory: bool,
+    timeout: Duration,
+) -> Result<DayBench, SolveError> {
+    let day_answers = runner::solve_with_timeout(solution, input.clone(), parts, timeout)?;
+    let answered: Vec<Part> = day_answers
+        .answers
+        .iter()
+        .map(|answer| answer.part)
+        .collect();
+    let runs = iterations.max(1) + usize::from(count_memory);
+    let budget = timeout.saturating_mul(u32::try_from(runs).unwrap_or(u32::MAX));
+    let mut bench = runner::run_with_timeout(solution.day(), budget, move || {
+        run(solution, &input, &answered, iterations, count_memory)
+    })?
+    .map_err(SolveError::Parse)?;
+    bench.failures = day_answers.failures;
+    Ok(bench)
+}
+
+fn measure_memory(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+) -> Result<MemoryUsage, ParseError> {
+    let (parsed, parse) = memory::measure(|| solution.parse(input));
+    let parsed = parsed?;
+    let parts = parts
+        .iter()
+        .map(|&part| (part, memory::measure(|| solution.solve(part, &*parsed)).1))
+        .collect();
+    Ok(MemoryUsage { parse, parts })
+}
+
+/// Prints the timings of every stage, followed by what it allocated for the
+/// results that counted it.
+pub fn print_table(results: &[DayBench]) {
+    let mut w = std::io::stdout().lock();
+    let _ = write_table(&mut w, results);
+}
+
+pub fn write_table(w: &mut impl Write, results: &[DayBench]) -> std::io::Result<()> {
+    let with_memory = results.iter().any(|result| result.memory.is_some());
+    write!(
+        w,
+        "{:>3}  {:<24}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "Day", "Title", "Stage", "Min", "Median", "Max"
+    )?;
+    if with_memory {
+        write!(w, "  {:>10}  {:>10}  {:>8}", "Peak", "Allocated", "Allocs")?;
+    }
+    writeln!(w)?;
+    for result in results {
+        let stages = std::iter::once((String::from("parse"), result.parse)).chain(
+            result
+                .parts
+                .iter()
+                .map(|&(part, stats)| (format!("part {}", part.number()), stats)),
+        );
+        let memory = result.memory.as_ref().map(|memory| {
+            std::iter::once(memory.parse)
+                .chain(memory.parts.iter().map(|&(_, usage)| usage))
+                .collect::<Vec<_>>()
+        });
+        for (i, (stage, stats)) in stages.enumerate() {
+            write!(
+                w,
+                "{:>3}  {:<24}  {:<6}  {:>10}  {:>10}  {:>10}",
+                result.puzzle.day,
+                result.puzzle.title,
+                stage,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max),
+            )?;
+            if let Some(memory) = &memory {
+                let usage = memory[i];
+                write!(
+                    w,
+                    "  {:>10}  {:>10}  {:>8}",
+                    memory::format_bytes(usage.peak),
+                    memory::format_bytes(usage.total),
+                    usage.count
+                )?;
+            }
+            writeln!(w)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SeaCucumber;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(
+            [5, 1, 4, 2, 3]
+                .iter()
+                .map(|&ms| Duration::from_millis(ms))
+                .collect(),
+        );
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.35µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.56ms");
+        assert_eq!(format_duration(Duration::from_millis(1_230)), "1.23s");
+    }
+
+    #[test]
+    fn test_run_times_every_stage() {
+        let result = run(
+            &crate::y2021::day_06::Day06,
+            "3,4,3,1,2",
+            &Part::ALL,
+            3,
+            false,
+        )
+        .unwrap();
+        assert_eq!(result.puzzle.day, 6);
+        assert!(result.parse.min <= result.parse.max);
+        assert_eq!(result.parts.len(), 2);
+        assert_eq!(result.memory, None);
+    }
+
+    #[test]
+    fn test_run_counts_memory() {
+        let result = run(
+            &crate::y2021::day_06::Day06,
+            "3,4,3,1,2",
+            &[Part::One],
+            1,
+            true,
+        )
+        .unwrap();
+        let memory = result.memory.unwrap();
+        assert!(memory.parse.count > 0);
+        assert!(memory.parse.peak <= memory.parse.total);
+        assert_eq!(memory.parts[0].0, Part::One);
+        assert!(memory.parts[0].1.total > 0);
+    }
+
+    #[test]
+    fn test_run_with_timeout_keeps_the_parts_that_answered() {
+        let timeout = Duration::from_millis(100);
+        let sleepy = &SeaCucumber {
+            delay_ms: [0, 1000],
+            panics: [None, None],
+        };
+        let result =
+            run_with_timeout(sleepy, String::new(), &Part::ALL, 3, false, timeout).unwrap();
+        assert_eq!(result.parts.len(), 1);
+        assert_eq!(result.parts[0].0, Part::One);
+        assert_eq!(
+            result.failures,
+            [PartFailure {
+                part: Part::Two,
+                error: SolveError::TimedOut(timeout),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_write_table_with_memory() {
+        let stats = Stats::new(vec![Duration::from_micros(3)]);
+        let usage = |peak, total, count| Usage { peak, total, count };
+        let result = DayBench {
+            puzzle: crate::y2021::day_06::Day06.info(),
+            parse: stats,
+            parts: vec![(Part::One, stats), (Part::Two, stats)],
+            memory: Some(MemoryUsage {
+                parse: usage(64, 100, 3),
+                parts: vec![
+                    (Part::One, usage(2048, 4096, 10)),
+                    (Part::Two, usage(0, 0, 0)),
+                ],
+            }),
+            failures: Vec::new(),
+        };
+        let mut out = Vec::new();
+        write_table(&mut out, &[result]).unwrap();
+        let table = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].ends_with("Peak   Allocated    Allocs"));
+        assert!(lines[2].contains("part 1"));
+        assert!(lines[2].ends_with("2.00KiB     4.00KiB        10"));
+    }
+}
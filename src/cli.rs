@@ -0,0 +1,742 @@
+use advent_of_code_2021::output::Format;
+use advent_of_code_2021::parallel;
+use advent_of_code_2021::report::ReportFormat;
+use advent_of_code_2021::runner;
+use advent_of_code_2021::solution::Part;
+use advent_of_code_2021::trace::{Level, LevelError};
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "Usage: advent_of_code_2021 [OPTIONS]
+       advent_of_code_2021 new-day <DAY> <TITLE> [--year <YEAR>]
+       advent_of_code_2021 report <PATH> [--format <FORMAT>] [--year <YEAR>]
+                                         [--input-dir <DIR>]
+
+Commands:
+  new-day <DAY> <TITLE>  Create src/yYYYY/day_NN.rs and an input placeholder,
+                         and register the day
+  report <PATH>          Solve every day and write a summary of the answers and
+                         timings to PATH, as markdown or html [default: from
+                         the extension of PATH]
+
+Options:
+  -y, --year <YEAR>      Run the puzzles of YEAR [default: the latest year]
+  -d, --day <DAY>        Run only the given day (may be repeated)
+  -p, --part <PART>      Run only part 1 or part 2
+  -i, --input <PATH>     Read the puzzle input from PATH, or from stdin if PATH is -
+      --input-dir <DIR>  Read day_XX_input.txt files from DIR/<YEAR>, or from DIR
+                         itself if it has no such subdirectory
+                         (defaults to $AOC_INPUT_DIR, then the crate's assets)
+  -a, --all              Run every registered day (the default)
+  -f, --format <FORMAT>  Print answers as text, json or csv [default: text]
+  -j, --jobs <N>         Solve days on N worker threads [default: 1]
+      --parallel         Solve days on one worker thread per CPU
+  -v, --verify           Compare answers against the expected answers file,
+                         skipping days it has no answers for
+      --answers <PATH>   Expected answers file [default: <input dir>/answers.txt]
+  -b, --bench            Time parsing and solving instead of printing answers
+  -n, --iterations <N>   Number of benchmark iterations [default: 10]
+  -m, --memory           With --bench, also count the peak and total memory
+                         each stage allocates
+  -w, --watch            Re-run the selected day whenever its input file changes
+      --repl             Explore the selected day's parsed input interactively
+      --serve            Solve inputs posted to
+                         http://127.0.0.1:<PORT>/<YEAR>/day/<DAY>/part/<PART>
+      --port <PORT>      Port for --serve [default: 2021]
+      --timeout <SECS>   Give up on a day that takes longer than SECS seconds to
+                         solve, and go on with the others [default: 15]. A day
+                         given up on keeps running in the background, and
+                         while 8 of those are running, new days and --serve
+                         requests are refused
+  -g, --generate <SEED>  Print a random input for the selected day instead
+      --size <N>         Size of the generated input [default: per day]
+  -t, --trace <LEVEL>    Print the solvers' intermediate state to stderr, at
+                         level info, debug or trace (defaults to $AOC_TRACE)
+  -h, --help             Print this help";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NewDay {
+    pub year: Option<u16>,
+    pub day: u8,
+    pub title: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    pub path: PathBuf,
+    pub format: ReportFormat,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Options {
+    pub year: Option<u16>,
+    pub days: Vec<u8>,
+    pub part: Option<Part>,
+    pub input: Option<InputSource>,
+    pub input_dir: Option<PathBuf>,
+    pub all: bool,
+    pub format: Format,
+    pub jobs: usize,
+    pub verify: bool,
+    pub answers: Option<PathBuf>,
+    pub bench: bool,
+    pub iterations: usize,
+    pub memory: bool,
+    pub watch: bool,
+    pub repl: bool,
+    pub serve: bool,
+    pub port: Option<u16>,
+    pub timeout: Duration,
+    pub generate: Option<u64>,
+    pub size: Option<usize>,
+    pub new_day: Option<NewDay>,
+    pub report: Option<Report>,
+    pub trace: Option<Level>,
+    pub help: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            year: None,
+            days: Vec::new(),
+            part: None,
+            input: None,
+            input_dir: None,
+            all: false,
+            format: Format::Text,
+            jobs: 1,
+            verify: false,
+            answers: None,
+            bench: false,
+            iterations: DEFAULT_ITERATIONS,
+            memory: false,
+            watch: false,
+            repl: false,
+            serve: false,
+            port: None,
+            timeout: runner::DEFAULT_TIMEOUT,
+            generate: None,
+            size: None,
+            new_day: None,
+            report: None,
+            trace: None,
+            help: false,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CliError {
+    message: String,
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl CliError {
+    fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl Options {
+    pub fn parse<I>(args: I) -> Result<Self, CliError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut options = Options::default();
+        let mut args = args.into_iter().peekable();
+        if args.peek().map(String::as_str) == Some("new-day") {
+            args.next();
+            options.new_day = Some(Self::parse_new_day(args)?);
+            return Ok(options);
+        }
+        if args.peek().map(String::as_str) == Some("report") {
+            args.next();
+            Self::parse_report(args, &mut options)?;
+            return Ok(options);
+        }
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-y" | "--year" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.year = Some(Self::year(&value)?);
+                }
+                "-d" | "--day" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.days.push(Self::day(&value)?);
+                }
+                "-p" | "--part" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.part = Some(match value.as_str() {
+                        "1" => Part::One,
+                        "2" => Part::Two,
+                        _ => return Err(CliError::new(format!("invalid part: {}", value))),
+                    });
+                }
+                "-i" | "--input" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.input = Some(match value.as_str() {
+                        "-" => InputSource::Stdin,
+                        _ => InputSource::File(PathBuf::from(value)),
+                    });
+                }
+                "--input-dir" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.input_dir = Some(PathBuf::from(value));
+                }
+                "-a" | "--all" => options.all = true,
+                "-f" | "--format" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.format = match value.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        "csv" => Format::Csv,
+                        _ => return Err(CliError::new(format!("invalid format: {}", value))),
+                    };
+                }
+                "-j" | "--jobs" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.jobs = value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&jobs| jobs > 0)
+                        .ok_or_else(|| CliError::new(format!("invalid job count: {}", value)))?;
+                }
+                "--parallel" => options.jobs = parallel::available_jobs(),
+                "-v" | "--verify" => options.verify = true,
+                "--answers" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.answers = Some(PathBuf::from(value));
+                }
+                "-b" | "--bench" => options.bench = true,
+                "-n" | "--iterations" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.iterations = value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&iterations| iterations > 0)
+                        .ok_or_else(|| {
+                            CliError::new(format!("invalid iteration count: {}", value))
+                        })?;
+                }
+                "-m" | "--memory" => options.memory = true,
+                "-w" | "--watch" => options.watch = true,
+                "--repl" => options.repl = true,
+                "--serve" => options.serve = true,
+                "--port" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.port = Some(
+                        value
+                            .parse::<u16>()
+                            .map_err(|_| CliError::new(format!("invalid port: {}", value)))?,
+                    );
+                }
+                "--timeout" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.timeout = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|&secs| secs > 0.0)
+                        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                        .ok_or_else(|| CliError::new(format!("invalid timeout: {}", value)))?;
+                }
+                "-g" | "--generate" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.generate = Some(
+                        value
+                            .parse::<u64>()
+                            .map_err(|_| CliError::new(format!("invalid seed: {}", value)))?,
+                    );
+                }
+                "--size" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.size = Some(
+                        value
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|&size| size > 0)
+                            .ok_or_else(|| CliError::new(format!("invalid size: {}", value)))?,
+                    );
+                }
+                "-t" | "--trace" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.trace = Some(
+                        value
+                            .parse()
+                            .map_err(|e: LevelError| CliError::new(e.to_string()))?,
+                    );
+                }
+                "-h" | "--help" => options.help = true,
+                _ => return Err(CliError::new(format!("unexpected argument: {}", arg))),
+            }
+        }
+
+        if options.all && !options.days.is_empty() {
+            return Err(CliError::new(String::from(
+                "--all cannot be combined with --day",
+            )));
+        }
+        if options.verify && options.bench {
+            return Err(CliError::new(String::from(
+                "--verify cannot be combined with --bench",
+            )));
+        }
+        if options.memory && !options.bench {
+            return Err(CliError::new(String::from("--memory requires --bench")));
+        }
+        if options.bench && options.trace.is_some() {
+            return Err(CliError::new(String::from(
+                "--trace cannot be combined with --bench",
+            )));
+        }
+        if options.bench && options.jobs > 1 {
+            return Err(CliError::new(String::from(
+                "--bench always runs on a single thread",
+            )));
+        }
+        if (options.verify || options.bench) && options.format != Format::Text {
+            return Err(CliError::new(String::from(
+                "--format cannot be combined with --verify or --bench",
+            )));
+        }
+        if options.input.is_some() && options.days.len() != 1 {
+            return Err(CliError::new(String::from(
+                "--input requires exactly one --day",
+            )));
+        }
+        if options.watch {
+            if options.days.len() != 1 {
+                return Err(CliError::new(String::from(
+                    "--watch requires exactly one --day",
+                )));
+            }
+            if options.input == Some(InputSource::Stdin) {
+                return Err(CliError::new(String::from(
+                    "--watch needs an input file, not stdin",
+                )));
+            }
+            if options.verify || options.bench || options.generate.is_some() {
+                return Err(CliError::new(String::from(
+                    "--watch cannot be combined with --verify, --bench or --generate",
+                )));
+            }
+            if options.format != Format::Text {
+                return Err(CliError::new(String::from("--watch only prints text")));
+            }
+        }
+        if options.repl {
+            if options.days.len() != 1 {
+                return Err(CliError::new(String::from(
+                    "--repl requires exactly one --day",
+                )));
+            }
+            if options.input == Some(InputSource::Stdin) {
+                return Err(CliError::new(String::from(
+                    "--repl reads commands from stdin, so the input must be a file",
+                )));
+            }
+            if options.verify
+                || options.bench
+                || options.watch
+                || options.generate.is_some()
+                || options.format != Format::Text
+            {
+                return Err(CliError::new(String::from(
+                    "--repl cannot be combined with --verify, --bench, --watch, --generate or --format",
+                )));
+            }
+        }
+        if options.serve {
+            if options.year.is_some() || !options.days.is_empty() || options.input.is_some() {
+                return Err(CliError::new(String::from(
+                    "--serve takes the year, day and input from each request",
+                )));
+            }
+            if options.verify
+                || options.bench
+                || options.watch
+                || options.repl
+                || options.generate.is_some()
+                || options.format != Format::Text
+            {
+                return Err(CliError::new(String::from(
+                    "--serve cannot be combined with --verify, --bench, --watch, --repl, --generate or --format",
+                )));
+            }
+        } else if options.port.is_some() {
+            return Err(CliError::new(String::from("--port requires --serve")));
+        }
+        if options.generate.is_some() {
+            if options.days.len() != 1 {
+                return Err(CliError::new(String::from(
+                    "--generate requires exactly one --day",
+                )));
+            }
+            if options.input.is_some() || options.verify || options.bench {
+                return Err(CliError::new(String::from(
+                    "--generate cannot be combined with --input, --verify or --bench",
+                )));
+            }
+        } else if options.size.is_some() {
+            return Err(CliError::new(String::from("--size requires --generate")));
+        }
+        Ok(options)
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    fn parse_new_day<I>(mut args: I) -> Result<NewDay, CliError>
+    where
+        I: Iterator<Item = String>,
+    {
+        let day = Self::day(&Self::value("new-day", args.next())?)?;
+        let title = Self::value("new-day", args.next())?;
+        if title.trim().is_empty() {
+            return Err(CliError::new(String::from("the title must not be empty")));
+        }
+        let year = match args.next() {
+            Some(arg) if arg == "-y" || arg == "--year" => {
+                Some(Self::year(&Self::value(&arg, args.next())?)?)
+            }
+            Some(arg) => return Err(CliError::new(format!("unexpected argument: {}", arg))),
+            None => None,
+        };
+        if let Some(arg) = args.next() {
+            return Err(CliError::new(format!("unexpected argument: {}", arg)));
+        }
+        Ok(NewDay { year, day, title })
+    }
+
+    fn parse_report<I>(mut args: I, options: &mut Options) -> Result<(), CliError>
+    where
+        I: Iterator<Item = String>,
+    {
+        let mut path = None;
+        let mut format = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-f" | "--format" => {
+                    let value = Self::value(&arg, args.next())?;
+                    format = Some(match value.as_str() {
+                        "markdown" | "md" => ReportFormat::Markdown,
+                        "html" => ReportFormat::Html,
+                        _ => {
+                            return Err(CliError::new(format!("invalid report format: {}", value)))
+                        }
+                    });
+                }
+                "-y" | "--year" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.year = Some(Self::year(&value)?);
+                }
+                "--input-dir" => {
+                    let value = Self::value(&arg, args.next())?;
+                    options.input_dir = Some(PathBuf::from(value));
+                }
+                _ if path.is_none() && !arg.starts_with('-') => path = Some(PathBuf::from(arg)),
+                _ => return Err(CliError::new(format!("unexpected argument: {}", arg))),
+            }
+        }
+        let path = path.ok_or_else(|| CliError::new(String::from("missing path for report")))?;
+        let format = match format.or_else(|| ReportFormat::from_path(&path)) {
+            Some(format) => format,
+            None => {
+                return Err(CliError::new(String::from(
+                    "cannot tell the report format from the file name, pass --format",
+                )))
+            }
+        };
+        options.report = Some(Report { path, format });
+        Ok(())
+    }
+
+    fn year(value: &str) -> Result<u16, CliError> {
+        value
+            .parse::<u16>()
+            .ok()
+            .filter(|&year| year >= 2015)
+            .ok_or_else(|| CliError::new(format!("invalid year: {}", value)))
+    }
+
+    fn day(value: &str) -> Result<u8, CliError> {
+        value
+            .parse::<u8>()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| CliError::new(format!("invalid day: {}", value)))
+    }
+
+    fn value(flag: &str, value: Option<String>) -> Result<String, CliError> {
+        value.ok_or_else(|| CliError::new(format!("missing value for {}", flag)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, CliError> {
+        Options::parse(args.iter().map(|&arg| String::from(arg)))
+    }
+
+    #[test]
+    fn test_parse_no_arguments() {
+        assert_eq!(parse(&[]), Ok(Options::default()));
+    }
+
+    #[test]
+    fn test_parse_day_and_part() {
+        let options = parse(&["--day", "9", "--part", "2"]).unwrap();
+        assert_eq!(options.days, vec![9]);
+        assert_eq!(options.part, Some(Part::Two));
+    }
+
+    #[test]
+    fn test_parse_repeated_day() {
+        let options = parse(&["-d", "1", "-d", "16"]).unwrap();
+        assert_eq!(options.days, vec![1, 16]);
+    }
+
+    #[test]
+    fn test_parse_input_file() {
+        let options = parse(&["--day", "4", "--input", "boards.txt"]).unwrap();
+        assert_eq!(
+            options.input,
+            Some(InputSource::File(PathBuf::from("boards.txt")))
+        );
+    }
+
+    #[test]
+    fn test_parse_input_stdin() {
+        let options = parse(&["--day", "4", "--input", "-"]).unwrap();
+        assert_eq!(options.input, Some(InputSource::Stdin));
+    }
+
+    #[test]
+    fn test_parse_input_dir() {
+        let options = parse(&["--input-dir", "inputs"]).unwrap();
+        assert_eq!(options.input_dir, Some(PathBuf::from("inputs")));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let options = parse(&["--format", "csv"]).unwrap();
+        assert_eq!(options.format, Format::Csv);
+        assert!(parse(&["--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_jobs() {
+        assert_eq!(parse(&["--jobs", "4"]).unwrap().jobs, 4);
+        assert!(parse(&["--parallel"]).unwrap().jobs >= 1);
+        assert!(parse(&["--jobs", "0"]).is_err());
+        assert!(parse(&["--jobs", "2", "--bench"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let options = parse(&["--verify", "--answers", "expected.txt"]).unwrap();
+        assert!(options.verify);
+        assert_eq!(options.answers, Some(PathBuf::from("expected.txt")));
+        assert!(parse(&["--verify", "--bench"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let options = parse(&["--bench", "--iterations", "25"]).unwrap();
+        assert!(options.bench);
+        assert_eq!(options.iterations, 25);
+    }
+
+    #[test]
+    fn test_parse_trace() {
+        assert_eq!(parse(&["-t", "debug"]).unwrap().trace, Some(Level::Debug));
+        assert_eq!(
+            parse(&["--trace", "loud"]).unwrap_err().to_string(),
+            "invalid trace level: loud, expected info, debug or trace"
+        );
+        assert!(parse(&["--trace", "info", "--bench"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_memory() {
+        assert!(parse(&["--bench", "-m"]).unwrap().memory);
+        assert!(parse(&["--memory"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse(&[]).unwrap().timeout, runner::DEFAULT_TIMEOUT);
+        assert_eq!(
+            parse(&["--timeout", "0.5"]).unwrap().timeout,
+            Duration::from_millis(500)
+        );
+        assert!(parse(&["--timeout", "0"]).is_err());
+        assert!(parse(&["--timeout", "inf"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_zero_iterations() {
+        assert!(parse(&["--bench", "-n", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_input_without_day() {
+        assert!(parse(&["--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_all_with_day() {
+        assert!(parse(&["--all", "--day", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_part() {
+        assert!(parse(&["--day", "1", "--part", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_day() {
+        assert!(parse(&["--day", "26"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_generate() {
+        let options = parse(&["--day", "9", "--generate", "42", "--size", "500"]).unwrap();
+        assert_eq!(options.generate, Some(42));
+        assert_eq!(options.size, Some(500));
+    }
+
+    #[test]
+    fn test_parse_generate_without_day() {
+        assert!(parse(&["--generate", "42"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_size_without_generate() {
+        assert!(parse(&["--day", "9", "--size", "500"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        let options = parse(&["--day", "4", "--watch"]).unwrap();
+        assert!(options.watch);
+    }
+
+    #[test]
+    fn test_parse_watch_without_day() {
+        assert!(parse(&["--watch"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_watch_stdin() {
+        assert!(parse(&["--day", "4", "--watch", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_repl() {
+        let options = parse(&["--day", "9", "--repl"]).unwrap();
+        assert!(options.repl);
+        assert!(parse(&["--repl"]).is_err());
+        assert!(parse(&["--day", "9", "--repl", "--watch"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(parse(&["--year", "2021"]).unwrap().year, Some(2021));
+        assert_eq!(parse(&[]).unwrap().year, None);
+        assert!(parse(&["--year", "1999"]).is_err());
+        assert!(parse(&["--year", "next"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_serve() {
+        let options = parse(&["--serve", "--port", "8080"]).unwrap();
+        assert!(options.serve);
+        assert_eq!(options.port, Some(8080));
+        assert!(parse(&["--port", "8080"]).is_err());
+        assert!(parse(&["--serve", "--day", "9"]).is_err());
+        assert!(parse(&["--serve", "--port", "70000"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_new_day() {
+        let options = parse(&["new-day", "11", "Dumbo Octopus"]).unwrap();
+        assert_eq!(
+            options.new_day,
+            Some(NewDay {
+                year: None,
+                day: 11,
+                title: String::from("Dumbo Octopus"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_new_day_with_year() {
+        let options = parse(&["new-day", "1", "Report Repair", "--year", "2020"]).unwrap();
+        assert_eq!(options.new_day.unwrap().year, Some(2020));
+    }
+
+    #[test]
+    fn test_parse_report() {
+        let options = parse(&["report", "weekly.html", "--year", "2021"]).unwrap();
+        assert_eq!(
+            options.report,
+            Some(Report {
+                path: PathBuf::from("weekly.html"),
+                format: ReportFormat::Html,
+            })
+        );
+        assert_eq!(options.year, Some(2021));
+        let options = parse(&["report", "--format", "markdown", "weekly"]).unwrap();
+        assert_eq!(options.report.unwrap().format, ReportFormat::Markdown);
+    }
+
+    #[test]
+    fn test_parse_report_errors() {
+        assert!(parse(&["report"]).is_err());
+        assert!(parse(&["report", "weekly.txt"]).is_err());
+        assert!(parse(&["report", "a.md", "b.md"]).is_err());
+        assert!(parse(&["report", "a.md", "--day", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_new_day_without_title() {
+        assert!(parse(&["new-day", "11"]).is_err());
+        assert!(parse(&["new-day", "11", " "]).is_err());
+    }
+
+    #[test]
+    fn test_parse_new_day_extra_argument() {
+        assert!(parse(&["new-day", "11", "Dumbo Octopus", "--day", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_new_day_invalid_day() {
+        assert!(parse(&["new-day", "26", "Title"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_missing_value() {
+        assert!(parse(&["--day"]).is_err());
+    }
+}
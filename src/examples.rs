@@ -0,0 +1,105 @@
+//! The worked examples from the puzzle texts, kept as `day_XX.txt` fixtures
+//! in `assets/YYYY/examples` next to an `answers.txt` of their answers.
+
+use crate::input;
+use crate::solution::{DynSolution, Part};
+use crate::verify::ExpectedAnswers;
+use std::path::{Path, PathBuf};
+
+/// The directory holding the examples of `year`.
+pub fn dir(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("assets")
+        .join(year.to_string())
+        .join("examples")
+}
+
+pub fn path(year: u16, day: u8) -> PathBuf {
+    dir(year).join(format!("day_{:02}.txt", day))
+}
+
+pub fn answers_path(year: u16) -> PathBuf {
+    dir(year).join("answers.txt")
+}
+
+/// Solves `part` of the example of `solution` and compares it with the
+/// expected answer, describing whatever went wrong.
+pub fn check(solution: &dyn DynSolution, part: Part) -> Result<(), String> {
+    let info = solution.info();
+    let answers = input::read_file(&answers_path(info.year)).map_err(|e| e.to_string())?;
+    let answers = ExpectedAnswers::parse(&answers).map_err(|e| e.to_string())?;
+    let expected = answers.get(info.day, part.number()).ok_or_else(|| {
+        format!(
+            "no example answer for day {} part {} in {}",
+            info.day,
+            part.number(),
+            answers_path(info.year).display()
+        )
+    })?;
+    let example = input::read_file(&path(info.year, info.day)).map_err(|e| e.to_string())?;
+    let day_answers =
+        crate::runner::solve(solution, &example, &[part]).map_err(|e| e.to_string())?;
+    let answer = &day_answers.answers[0].answer;
+    if answer == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "day {} part {} example: expected {}, found {}",
+            info.day,
+            part.number(),
+            expected,
+            answer
+        ))
+    }
+}
+
+/// Generates a test per part that solves the example of `$solution`, with
+/// any attributes given after it, like `#[ignore]`.
+#[cfg(test)]
+macro_rules! example_tests {
+    ($solution:expr $(, #[$attribute:meta])*) => {
+        #[test]
+        $(#[$attribute])*
+        fn test_example_part_one() {
+            if let Err(e) = $crate::examples::check(&$solution, $crate::solution::Part::One) {
+                panic!("{}", e);
+            }
+        }
+
+        #[test]
+        $(#[$attribute])*
+        fn test_example_part_two() {
+            if let Err(e) = $crate::examples::check(&$solution, $crate::solution::Part::Two) {
+                panic!("{}", e);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use example_tests;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::QUICK;
+
+    #[test]
+    fn test_every_registered_day_has_an_example() {
+        for year in crate::registry::YEARS {
+            for solution in year.solutions.iter().filter(|s| !s.scaffolded()) {
+                for part in Part::ALL {
+                    if let Err(e) = check(*solution, part) {
+                        panic!("{}", e);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_missing_example() {
+        let error = check(&QUICK, Part::One).unwrap_err();
+        assert!(error.starts_with("no example answer for day 25 part 1"));
+    }
+}
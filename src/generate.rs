@@ -0,0 +1,549 @@
+//! Seeded generators for random puzzle inputs, for stress-testing solvers on
+//! inputs larger or stranger than the ones in `assets/`.
+
+use crate::y2021;
+
+/// A small deterministic random number generator (SplitMix64), so the same
+/// seed always produces the same input.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: std::ops::Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "empty range");
+        range.start + self.next_u64() % (range.end - range.start)
+    }
+
+    /// Returns true with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(0..denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range(0..i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// The size that roughly matches a real puzzle input for `day`.
+pub fn default_size(day: u8) -> usize {
+    match day {
+        1 => 2000,
+        4 => 100,
+        5 => 500,
+        6 => 300,
+        8 => 200,
+        9 | 10 => 100,
+        16 => 300,
+        _ => 1000,
+    }
+}
+
+/// Why [`generate`] could not produce an input.
+#[derive(Debug, PartialEq, Eq)]
+pub enum GenerateError {
+    /// The day has no generator.
+    UnknownDay { year: u16, day: u8 },
+    /// The day cannot generate an input of that size.
+    SizeOutOfRange { day: u8, size: usize, max: usize },
+}
+
+impl std::fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GenerateError::UnknownDay { year, day } => {
+                write!(f, "no input generator for day {} of {}", day, year)
+            }
+            GenerateError::SizeOutOfRange { day, size, max } => write!(
+                f,
+                "invalid size for day {}: {}, expected 1 to {}",
+                day, size, max
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {}
+
+/// The largest size the generator for `day` supports. Day 3 runs out of
+/// distinct 12-bit numbers, and days 2 and 7 keep their answers well inside
+/// an `i64`: day 2 stays below 3159 times the size squared and day 7 below
+/// two million per crab.
+fn max_size(day: u8) -> usize {
+    match day {
+        2 | 7 => 100_000,
+        3 => 1 << 12,
+        _ => usize::MAX,
+    }
+}
+
+/// Generates an input for `day` of `year`. What `size` counts depends on the
+/// day: lines for most days, boards for day 4, fish for day 6, crabs for day 7,
+/// rows and columns for day 9 and packets for day 16.
+pub fn generate(year: u16, day: u8, rng: &mut Rng, size: usize) -> Result<String, GenerateError> {
+    if year != y2021::YEAR {
+        return Err(GenerateError::UnknownDay { year, day });
+    }
+    if !(1..=max_size(day)).contains(&size) {
+        return Err(GenerateError::SizeOutOfRange {
+            day,
+            size,
+            max: max_size(day),
+        });
+    }
+    let input = match day {
+        1 => sonar_depths(rng, size),
+        2 => course(rng, size),
+        3 => diagnostic_report(rng, size),
+        4 => bingo(rng, size),
+        5 => vent_lines(rng, size),
+        6 => fish_timers(rng, size),
+        7 => crab_positions(rng, size),
+        8 => displays(rng, size),
+        9 => heightmap(rng, size),
+        10 => bracket_lines(rng, size),
+        16 => bits_transmission(rng, size),
+        _ => return Err(GenerateError::UnknownDay { year, day }),
+    };
+    Ok(input)
+}
+
+/// Depths that wander up and down, but mostly down. They stay below a third
+/// of `u16::MAX` so sums of three-measurement windows still fit in a `u16`.
+pub fn sonar_depths(rng: &mut Rng, size: usize) -> String {
+    let mut depth = rng.range(100..200) as i64;
+    let mut input = String::new();
+    for _ in 0..size {
+        input += &format!("{}\n", depth);
+        depth = (depth + rng.range(0..41) as i64 - 15).clamp(0, u16::MAX as i64 / 3);
+    }
+    input
+}
+
+/// Course steps whose aim never drops below zero or climbs above 39, so the
+/// depth grows by at most 351 a step.
+pub fn course(rng: &mut Rng, size: usize) -> String {
+    let mut aim = 0;
+    let mut input = String::new();
+    for _ in 0..size {
+        let value = rng.range(1..10);
+        let command = if rng.chance(1, 2) {
+            "forward"
+        } else if aim >= value && (aim > 30 || rng.chance(1, 2)) {
+            aim -= value;
+            "up"
+        } else {
+            aim += value;
+            "down"
+        };
+        input += &format!("{} {}\n", command, value);
+    }
+    input
+}
+
+/// Distinct 12-bit numbers, so `size` must be at most 4096.
+pub fn diagnostic_report(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<u16> = (0..1 << 12).collect();
+    rng.shuffle(&mut numbers);
+    numbers[..size]
+        .iter()
+        .map(|n| format!("{:012b}\n", n))
+        .collect()
+}
+
+/// A bingo game that draws every number from 0 to 99 and has `size` boards.
+/// Boards that tie for last place are redrawn so the last winner is unique.
+pub fn bingo(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<u8> = (0..100).collect();
+    rng.shuffle(&mut numbers);
+    let mut boards: Vec<Vec<u8>> = (0..size.max(1)).map(|_| bingo_board(rng)).collect();
+    loop {
+        let turns: Vec<usize> = boards
+            .iter()
+            .map(|board| bingo_winning_turn(&numbers, board))
+            .collect();
+        let last = *turns.iter().max().unwrap();
+        let tied: Vec<usize> = (0..boards.len()).filter(|&i| turns[i] == last).collect();
+        if tied.len() == 1 {
+            break;
+        }
+        for &i in tied.iter().skip(1) {
+            boards[i] = bingo_board(rng);
+        }
+    }
+
+    let mut input = numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    input.push('\n');
+    for board in boards {
+        input.push('\n');
+        for row in board.chunks(5) {
+            let row: Vec<String> = row.iter().map(|n| format!("{:>2}", n)).collect();
+            input += &row.join(" ");
+            input.push('\n');
+        }
+    }
+    input
+}
+
+fn bingo_board(rng: &mut Rng) -> Vec<u8> {
+    let mut cells: Vec<u8> = (0..100).collect();
+    rng.shuffle(&mut cells);
+    cells.truncate(25);
+    cells
+}
+
+/// The number of draws after which `board` has a complete row or column.
+fn bingo_winning_turn(numbers: &[u8], board: &[u8]) -> usize {
+    let turn = |n: u8| numbers.iter().position(|&drawn| drawn == n).unwrap() + 1;
+    (0..5)
+        .flat_map(|i| {
+            let row = (0..5).map(|j| turn(board[i * 5 + j])).max().unwrap();
+            let column = (0..5).map(|j| turn(board[j * 5 + i])).max().unwrap();
+            [row, column]
+        })
+        .min()
+        .unwrap()
+}
+
+/// Horizontal, vertical and 45 degree vent lines on a 1000 by 1000 floor.
+pub fn vent_lines(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut lines = 0;
+    while lines < size {
+        let (x1, y1) = (rng.range(0..1000) as i64, rng.range(0..1000) as i64);
+        let (dx, dy) = *rng.choose(&[
+            (1, 0),
+            (-1, 0),
+            (0, 1),
+            (0, -1),
+            (1, 1),
+            (1, -1),
+            (-1, 1),
+            (-1, -1),
+        ]);
+        let reach = |position: i64, direction: i64| match direction {
+            1 => 999 - position,
+            -1 => position,
+            _ => i64::MAX,
+        };
+        let max_length = reach(x1, dx).min(reach(y1, dy)).min(300);
+        if max_length == 0 {
+            continue;
+        }
+        let length = rng.range(1..max_length as u64 + 1) as i64;
+        input += &format!(
+            "{},{} -> {},{}\n",
+            x1,
+            y1,
+            x1 + dx * length,
+            y1 + dy * length
+        );
+        lines += 1;
+    }
+    input
+}
+
+/// Lanternfish timers from 1 to 5, like the real puzzle input.
+pub fn fish_timers(rng: &mut Rng, size: usize) -> String {
+    let timers: Vec<String> = (0..size.max(1))
+        .map(|_| rng.range(1..6).to_string())
+        .collect();
+    timers.join(",") + "\n"
+}
+
+/// Crab positions from 0 to 1999.
+pub fn crab_positions(rng: &mut Rng, size: usize) -> String {
+    let positions: Vec<String> = (0..size.max(1))
+        .map(|_| rng.range(0..2000).to_string())
+        .collect();
+    positions.join(",") + "\n"
+}
+
+const DIGIT_SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Seven-segment displays, each with its own wiring, its ten patterns in a
+/// random order and the segments of every pattern shuffled.
+pub fn displays(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut wiring: Vec<char> = ('a'..='g').collect();
+        rng.shuffle(&mut wiring);
+        let scramble = |rng: &mut Rng, digit: usize| {
+            let mut segments: Vec<char> = DIGIT_SEGMENTS[digit]
+                .chars()
+                .map(|c| wiring[(c as u8 - b'a') as usize])
+                .collect();
+            rng.shuffle(&mut segments);
+            segments.into_iter().collect::<String>()
+        };
+        let mut digits: Vec<usize> = (0..10).collect();
+        rng.shuffle(&mut digits);
+        let patterns: Vec<String> = digits.iter().map(|&d| scramble(rng, d)).collect();
+        let output: Vec<String> = (0..4)
+            .map(|_| {
+                let digit = rng.range(0..10) as usize;
+                scramble(rng, digit)
+            })
+            .collect();
+        input += &format!("{} | {}\n", patterns.join(" "), output.join(" "));
+    }
+    input
+}
+
+/// A square heightmap in which about half of the cells are 9, the walls
+/// between basins.
+pub fn heightmap(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        for _ in 0..size.max(1) {
+            let height = if rng.chance(1, 2) { 9 } else { rng.range(0..9) };
+            input += &height.to_string();
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// Navigation subsystem lines, about half of them corrupted by a wrong closing
+/// bracket and the rest incomplete. The number of incomplete lines is always
+/// odd, so there is a middle autocomplete score, and no more than a dozen
+/// chunks are ever open, so completion scores fit in 64 bits.
+pub fn bracket_lines(rng: &mut Rng, size: usize) -> String {
+    const OPEN: [char; 4] = ['(', '[', '{', '<'];
+    const CLOSE: [char; 4] = [')', ']', '}', '>'];
+    let size = size.max(1);
+    let mut incomplete = 0;
+    let mut input = String::new();
+    for i in 0..size {
+        let length = rng.range(20..110);
+        let corrupt = if i + 1 < size {
+            rng.chance(1, 2)
+        } else {
+            incomplete % 2 == 1
+        };
+        let corrupt_at = rng.range(1..length);
+        let mut stack = Vec::new();
+        let mut line = String::new();
+        for j in 0..length {
+            if corrupt && j >= corrupt_at && !stack.is_empty() {
+                break;
+            } else if stack.is_empty() || (stack.len() < 12 && rng.chance(3, 5)) {
+                let open = rng.range(0..4) as usize;
+                stack.push(open);
+                line.push(OPEN[open]);
+            } else {
+                line.push(CLOSE[stack.pop().unwrap()]);
+            }
+        }
+        if stack.is_empty() {
+            let open = rng.range(0..4) as usize;
+            stack.push(open);
+            line.push(OPEN[open]);
+        }
+        if corrupt {
+            let expected = CLOSE[*stack.last().unwrap()];
+            let wrong: Vec<char> = CLOSE.iter().copied().filter(|&c| c != expected).collect();
+            line.push(*rng.choose(&wrong));
+            // Whatever follows the illegal character is never read.
+            for _ in 0..rng.range(0..10) {
+                line.push(*rng.choose(&CLOSE));
+            }
+        } else {
+            incomplete += 1;
+        }
+        input += &line;
+        input.push('\n');
+    }
+    input
+}
+
+/// A BITS transmission whose outermost packet sums expressions built from
+/// about `size` packets in total. Products only multiply small literals, so
+/// the result fits in 64 bits.
+pub fn bits_transmission(rng: &mut Rng, size: usize) -> String {
+    let mut budget = size.max(2) - 1;
+    let mut children = Vec::new();
+    while budget > 0 && children.len() < 2047 {
+        children.push(bits_packet(rng, &mut budget, 0));
+    }
+    let mut bits = bits_operator(rng, 0, children);
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+    let hex: String = bits
+        .as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let value = nibble
+                .iter()
+                .fold(0, |acc, &bit| acc * 2 + (bit - b'0') as u32);
+            char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+    hex + "\n"
+}
+
+fn bits_packet(rng: &mut Rng, budget: &mut usize, depth: usize) -> String {
+    *budget = budget.saturating_sub(1);
+    if *budget == 0 || depth >= 8 || rng.chance(2, 5) {
+        let value = rng.range(0..4096);
+        return bits_literal(rng, value);
+    }
+    let type_id = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    let count = match type_id {
+        1 => rng.range(2..4) as usize,
+        5..=7 => 2,
+        _ => rng.range(1..5) as usize,
+    };
+    let children = (0..count)
+        .map(|_| {
+            if type_id == 1 {
+                let value = rng.range(1..10);
+                bits_literal(rng, value)
+            } else {
+                bits_packet(rng, budget, depth + 1)
+            }
+        })
+        .collect();
+    bits_operator(rng, type_id, children)
+}
+
+fn bits_literal(rng: &mut Rng, value: u64) -> String {
+    let mut bits = format!("{:03b}100", rng.range(0..8));
+    let groups = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+    for group in (0..groups).rev() {
+        let last = if group == 0 { '0' } else { '1' };
+        bits += &format!("{}{:04b}", last, (value >> (group * 4)) & 0xf);
+    }
+    bits
+}
+
+fn bits_operator(rng: &mut Rng, type_id: u64, children: Vec<String>) -> String {
+    let body: String = children.concat();
+    let mut bits = format!("{:03b}{:03b}", rng.range(0..8), type_id);
+    if body.len() < 1 << 15 && rng.chance(1, 2) {
+        bits += &format!("0{:015b}", body.len());
+    } else {
+        bits += &format!("1{:011b}", children.len());
+    }
+    bits + &body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_rng_range() {
+        let mut rng = Rng::new(7);
+        assert!((0..1000).all(|_| (3..5).contains(&rng.range(3..5))));
+    }
+
+    #[test]
+    fn test_generate_is_deterministic() {
+        let input = generate(2021, 9, &mut Rng::new(3), 10);
+        assert_eq!(input, generate(2021, 9, &mut Rng::new(3), 10));
+    }
+
+    #[test]
+    fn test_generate_unknown_day() {
+        assert_eq!(
+            generate(2021, 25, &mut Rng::new(0), 10),
+            Err(GenerateError::UnknownDay {
+                year: 2021,
+                day: 25
+            })
+        );
+        assert!(generate(2015, 1, &mut Rng::new(0), 10).is_err());
+    }
+
+    #[test]
+    fn test_generate_size_out_of_range() {
+        assert!(generate(2021, 3, &mut Rng::new(0), 4096).is_ok());
+        assert_eq!(
+            generate(2021, 3, &mut Rng::new(0), 4097)
+                .unwrap_err()
+                .to_string(),
+            "invalid size for day 3: 4097, expected 1 to 4096"
+        );
+        assert!(generate(2021, 1, &mut Rng::new(0), 0).is_err());
+    }
+
+    #[test]
+    fn test_generated_inputs_at_the_maximum_size() {
+        for solution in y2021::SOLUTIONS {
+            let size = max_size(solution.day());
+            if size == usize::MAX {
+                continue;
+            }
+            let input = generate(2021, solution.day(), &mut Rng::new(1), size).unwrap();
+            let parsed = solution.parse(&input).unwrap_or_else(|e| panic!("{}", e));
+            for part in Part::ALL {
+                solution.solve(part, &*parsed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_registered_day_solves_generated_inputs() {
+        for solution in y2021::SOLUTIONS.iter().filter(|s| !s.scaffolded()) {
+            for seed in 0..5 {
+                let input = generate(2021, solution.day(), &mut Rng::new(seed), 30)
+                    .unwrap_or_else(|e| panic!("{}", e));
+                let parsed = solution.parse(&input).unwrap_or_else(|e| panic!("{}", e));
+                for part in Part::ALL {
+                    solution.solve(part, &*parsed);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bingo_board_count() {
+        let input = bingo(&mut Rng::new(5), 200);
+        assert_eq!(input.lines().filter(|line| line.is_empty()).count(), 200);
+    }
+
+    #[test]
+    fn test_bits_transmission_size() {
+        let input = bits_transmission(&mut Rng::new(11), 50);
+        let packet = crate::y2021::day_16::Decoder::parse(&input).unwrap();
+        assert_eq!(packet.type_id(), 0);
+    }
+}
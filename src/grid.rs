@@ -0,0 +1,300 @@
+use crate::parse::ParseError;
+
+/// A rectangular map stored row by row, addressed by `(row, column)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row-major order.
+    ///
+    /// Panics if `cells` does not hold exactly `rows * columns` values.
+    pub fn new(rows: usize, columns: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), rows * columns, "grid size mismatch");
+        Self {
+            cells,
+            rows,
+            columns,
+        }
+    }
+
+    /// Parses a character map, one row per line, converting each character
+    /// with `cell`. Errors name `expected` when `cell` rejects a character.
+    pub fn parse<F>(day: u8, input: &str, expected: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let lines: Vec<&str> = input.lines().collect();
+        let columns = lines.first().map_or(0, |line| line.chars().count());
+        if columns == 0 {
+            return Err(ParseError::new(
+                day,
+                input,
+                input,
+                format!("expected a non-empty row, with each cell {}", expected),
+            ));
+        }
+        let mut cells = Vec::with_capacity(lines.len() * columns);
+        for &line in lines.iter() {
+            let count = line.chars().count();
+            if count != columns {
+                return Err(ParseError::new(
+                    day,
+                    input,
+                    line,
+                    format!("expected a row of {} cells, found {}", columns, count),
+                ));
+            }
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::new(
+                        day,
+                        input,
+                        &line[i..i + c.len_utf8()],
+                        format!("expected {}", expected),
+                    )
+                })?;
+                cells.push(value);
+            }
+        }
+        Ok(Self::new(lines.len(), columns, cells))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, row: usize, column: usize) -> bool {
+        row < self.rows && column < self.columns
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if self.contains(row, column) {
+            Some(&self.cells[row * self.columns + column])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        if self.contains(row, column) {
+            Some(&mut self.cells[row * self.columns + column])
+        } else {
+            None
+        }
+    }
+
+    /// Converts a row-major index into a `(row, column)` position.
+    pub fn position_of(&self, index: usize) -> (usize, usize) {
+        (index / self.columns, index % self.columns)
+    }
+
+    /// Converts a `(row, column)` position into a row-major index.
+    pub fn index_of(&self, (row, column): (usize, usize)) -> usize {
+        row * self.columns + column
+    }
+
+    /// The orthogonal neighbors of a cell that lie inside the grid, in
+    /// reading order: up, left, right, down.
+    pub fn neighbors(&self, row: usize, column: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(row, column, &[(-1, 0), (0, -1), (0, 1), (1, 0)])
+    }
+
+    /// All eight neighbors of a cell that lie inside the grid, in reading
+    /// order.
+    pub fn neighbors_with_diagonals(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(
+            row,
+            column,
+            &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn offsets(
+        &self,
+        row: usize,
+        column: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (rows, columns) = (self.rows, self.columns);
+        offsets
+            .iter()
+            .filter_map(move |&(row_offset, column_offset)| {
+                let row = row.checked_add_signed(row_offset)?;
+                let column = column.checked_add_signed(column_offset)?;
+                (row < rows && column < columns).then_some((row, column))
+            })
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        assert!(row < self.rows, "row outside of grid");
+        self.cells[row * self.columns..(row + 1) * self.columns].iter()
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.columns, "column outside of grid");
+        self.cells[column..].iter().step_by(self.columns)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every cell along with its `(row, column)` position, in reading order.
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let columns = self.columns;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / columns, i % columns), cell))
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        self.get(row, column).expect("position outside of grid")
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        self.get_mut(row, column).expect("position outside of grid")
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "123
+456";
+
+    fn grid() -> Grid<u32> {
+        Grid::parse(0, INPUT, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.rows(), grid.columns()), (2, 3));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get(2, 0), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_cell() {
+        let error = Grid::parse(9, "123\n4x6", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "expected a digit");
+    }
+
+    #[test]
+    fn test_parse_uneven_rows() {
+        let error = Grid::parse(9, "123\n45", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let error = Grid::parse(9, "", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.message,
+            "expected a non-empty row, with each cell a digit"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "column outside of grid")]
+    fn test_column_outside_of_grid() {
+        grid().column(3).count();
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors(0, 1).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 2), (1, 1)]
+        );
+        assert_eq!(
+            grid.neighbors(1, 2).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_neighbors_with_diagonals() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors_with_diagonals(1, 0).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    }
+
+    #[test]
+    fn test_index_and_position() {
+        let grid = grid();
+        assert_eq!(grid.position_of(4), (1, 1));
+        assert_eq!(grid.index_of((1, 1)), 4);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(grid().to_string(), INPUT);
+    }
+}
@@ -0,0 +1,147 @@
+use std::ffi::OsString;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub struct InputError {
+    path: PathBuf,
+    source: std::io::Error,
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "could not read input file {}: {}",
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Finds puzzle inputs named `day_XX_input.txt` inside a base directory. Each
+/// year's inputs live in a `YYYY` subdirectory of the base; a base without one
+/// holds the inputs of whichever year is asked for.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InputLocator {
+    base: PathBuf,
+}
+
+impl InputLocator {
+    /// Uses `input_dir` if given, then the `AOC_INPUT_DIR` environment
+    /// variable, and finally the `assets` directory of this crate.
+    pub fn new(input_dir: Option<PathBuf>) -> Self {
+        Self::resolve(input_dir, std::env::var_os(INPUT_DIR_VAR))
+    }
+
+    fn resolve(input_dir: Option<PathBuf>, env_dir: Option<OsString>) -> Self {
+        let base = input_dir
+            .or_else(|| env_dir.filter(|dir| !dir.is_empty()).map(PathBuf::from))
+            .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("assets"));
+        Self { base }
+    }
+
+    /// The directory holding the inputs of `year`.
+    pub fn dir(&self, year: u16) -> PathBuf {
+        let dir = self.base.join(year.to_string());
+        if dir.is_dir() {
+            dir
+        } else {
+            self.base.clone()
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir(year).join(format!("day_{:02}_input.txt", day))
+    }
+
+    /// The file of known-correct answers for the inputs of `year`.
+    pub fn answers_path(&self, year: u16) -> PathBuf {
+        self.dir(year).join("answers.txt")
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        read_file(&self.path(year, day))
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|source| InputError {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|source| InputError {
+            path: PathBuf::from("<stdin>"),
+            source,
+        })?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_pads_day() {
+        let locator = InputLocator::resolve(Some(PathBuf::from("inputs")), None);
+        assert_eq!(
+            locator.path(2021, 9),
+            Path::new("inputs").join("day_09_input.txt")
+        );
+    }
+
+    #[test]
+    fn test_input_dir_overrides_env() {
+        let locator =
+            InputLocator::resolve(Some(PathBuf::from("cli")), Some(OsString::from("env")));
+        assert_eq!(
+            locator.path(2021, 1),
+            Path::new("cli").join("day_01_input.txt")
+        );
+    }
+
+    #[test]
+    fn test_env_overrides_manifest_dir() {
+        let locator = InputLocator::resolve(None, Some(OsString::from("env")));
+        assert_eq!(
+            locator.path(2021, 1),
+            Path::new("env").join("day_01_input.txt")
+        );
+    }
+
+    #[test]
+    fn test_defaults_to_crate_assets() {
+        let locator = InputLocator::resolve(None, None);
+        assert!(locator.read(2021, 1).is_ok());
+    }
+
+    #[test]
+    fn test_year_subdirectory() {
+        let locator = InputLocator::resolve(None, None);
+        assert_eq!(
+            locator.path(2021, 1),
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/2021/day_01_input.txt")
+        );
+        assert_eq!(
+            locator.answers_path(2021),
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/2021/answers.txt")
+        );
+    }
+
+    #[test]
+    fn test_missing_file_names_path() {
+        let locator = InputLocator::resolve(Some(PathBuf::from("missing")), None);
+        let message = locator.read(2021, 25).unwrap_err().to_string();
+        assert!(message.contains(&locator.path(2021, 25).display().to_string()));
+    }
+}
@@ -0,0 +1,86 @@
+//! Commands that let the REPL poke at a day's parsed model.
+
+use std::str::FromStr;
+
+/// A command a day understands, for the REPL's help.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Command {
+    pub usage: &'static str,
+    pub description: &'static str,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InspectError {
+    UnknownCommand,
+    /// The arguments did not fit the command; holds its usage.
+    Usage(&'static str),
+    Invalid(String),
+}
+
+impl std::fmt::Display for InspectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            InspectError::UnknownCommand => write!(f, "unknown command, try help"),
+            InspectError::Usage(usage) => write!(f, "usage: {}", usage),
+            InspectError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for InspectError {}
+
+/// Parses the only argument of a command, failing with `usage` if there is
+/// not exactly one or it does not parse.
+pub fn argument<T: FromStr>(args: &[&str], usage: &'static str) -> Result<T, InspectError> {
+    match args {
+        [arg] => arg.parse().map_err(|_| InspectError::Usage(usage)),
+        _ => Err(InspectError::Usage(usage)),
+    }
+}
+
+/// Fails with `usage` unless the command was given no arguments.
+pub fn no_arguments(args: &[&str], usage: &'static str) -> Result<(), InspectError> {
+    if args.is_empty() {
+        Ok(())
+    } else {
+        Err(InspectError::Usage(usage))
+    }
+}
+
+/// Looks up `index` in `items`, naming `what` was out of range otherwise.
+pub fn item<'a, T>(items: &'a [T], index: usize, what: &str) -> Result<&'a T, InspectError> {
+    items.get(index).ok_or_else(|| {
+        InspectError::Invalid(format!("no {} {}, there are {}", what, index, items.len()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_argument() {
+        assert_eq!(argument::<u16>(&["18"], "simulate DAYS"), Ok(18));
+        assert_eq!(
+            argument::<u16>(&["x"], "simulate DAYS"),
+            Err(InspectError::Usage("simulate DAYS"))
+        );
+        assert!(argument::<u16>(&[], "simulate DAYS").is_err());
+        assert!(argument::<u16>(&["1", "2"], "simulate DAYS").is_err());
+    }
+
+    #[test]
+    fn test_no_arguments() {
+        assert_eq!(no_arguments(&[], "low-points"), Ok(()));
+        assert!(no_arguments(&["1"], "low-points").is_err());
+    }
+
+    #[test]
+    fn test_item() {
+        assert_eq!(item(&[1, 2], 1, "board"), Ok(&2));
+        assert_eq!(
+            item(&[1, 2], 2, "board").unwrap_err().to_string(),
+            "no board 2, there are 2"
+        );
+    }
+}
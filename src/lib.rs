@@ -0,0 +1,35 @@
+//! Solutions to Advent of Code, one module per year.
+//!
+//! Every `yYYYY::day_XX` module exposes its puzzle model (for example
+//! [`y2021::day_16::Decoder`] or [`y2021::day_10::SyntaxChecker`]) along with a
+//! unit struct such as [`y2021::day_01::Day01`] that implements
+//! [`solution::Solution`]. The [`registry`] lists those structs by year so a
+//! runner can pick days by number.
+
+// Lets code shared with the binary, like `test_support`, name this crate
+// the same way in both.
+extern crate self as advent_of_code_2021;
+
+pub mod bench;
+pub mod examples;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod inspect;
+pub mod memory;
+pub mod output;
+pub mod parallel;
+pub mod parse;
+pub mod puzzle;
+pub mod reference;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod server;
+pub mod solution;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod trace;
+pub mod verify;
+pub mod watch;
+pub mod y2021;
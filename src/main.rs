@@ -1,26 +1,555 @@
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_16;
+mod cli;
+mod repl;
+mod scaffold;
+// Not every fixture is used by the binary's own tests.
+#[cfg(test)]
+#[allow(dead_code)]
+mod test_support;
+
+use advent_of_code_2021::generate::{self, Rng};
+use advent_of_code_2021::input::{self, InputError, InputLocator};
+use advent_of_code_2021::memory::CountingAllocator;
+use advent_of_code_2021::output::{self, Format};
+use advent_of_code_2021::report::{self, DayReport};
+use advent_of_code_2021::runner::{self, DayAnswers};
+use advent_of_code_2021::solution::DynSolution;
+use advent_of_code_2021::verify::{self, ExpectedAnswers, Outcome};
+use advent_of_code_2021::watch::{self, Watcher};
+use advent_of_code_2021::{bench, parallel, registry, server, trace};
+use cli::{InputSource, NewDay, Options, Report};
+use std::error::Error;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+type DayResult = Result<DayAnswers, Box<dyn Error + Send + Sync>>;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 fn main() {
-    println!("Advent of Code 2021");
-    day_01::print_answers();
-    day_02::print_answers();
-    day_03::print_answers();
-    day_04::print_answers();
-    day_05::print_answers();
-    day_06::print_answers();
-    day_07::print_answers();
-    day_08::print_answers();
-    day_09::print_answers();
-    day_10::print_answers();
-    day_16::print_answers();
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if options.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+    if let Err(e) = trace::init(options.trace) {
+        eprintln!("error: {}", e);
+        std::process::exit(2);
+    }
+
+    if let Some(new_day) = &options.new_day {
+        create_day(new_day);
+        return;
+    }
+
+    let year = options.year.unwrap_or_else(registry::latest_year);
+    let solutions = match select_solutions(year, &options) {
+        Ok(solutions) => solutions,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(2);
+        }
+    };
+
+    if let Some(seed) = options.generate {
+        let day = solutions[0].day();
+        let size = options.size.unwrap_or_else(|| generate::default_size(day));
+        match generate::generate(year, day, &mut Rng::new(seed), size) {
+            Ok(input) => print!("{}", input),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                std::process::exit(2);
+            }
+        }
+        return;
+    }
+
+    let inputs = Inputs {
+        year,
+        locator: InputLocator::new(options.input_dir.clone()),
+    };
+    if let Some(report) = &options.report {
+        if !write_report(report, &solutions, &inputs, &options) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    if options.serve {
+        if !serve(
+            options.port.unwrap_or(server::DEFAULT_PORT),
+            options.timeout,
+        ) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    if options.format == Format::Text {
+        println!("Advent of Code {}", year);
+    }
+    if options.repl {
+        if !explore_day(solutions[0], &inputs, &options) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    if options.watch {
+        watch_day(solutions[0], &inputs, &options);
+    }
+    let passed = if options.bench {
+        bench_days(&solutions, &inputs, &options)
+    } else {
+        let start = Instant::now();
+        let results = parallel::map(&solutions, options.jobs, |&solution| {
+            solve_input(solution, &inputs, &options)
+        });
+        let wall_clock = start.elapsed();
+
+        let passed = if options.verify {
+            verify_days(&solutions, &results, &inputs, &options)
+        } else {
+            print_days(&solutions, &results, &options)
+        };
+        if options.jobs > 1 {
+            print_parallel_summary(&results, wall_clock, &options);
+        }
+        passed
+    };
+    if !passed {
+        std::process::exit(1);
+    }
+}
+
+fn create_day(new_day: &NewDay) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let year = new_day.year.unwrap_or_else(registry::latest_year);
+    match scaffold::create(root, year, new_day.day, &new_day.title) {
+        Ok(paths) => {
+            println!("Created day {}: {}", new_day.day, new_day.title);
+            for path in paths {
+                println!("  {}", path.strip_prefix(root).unwrap_or(&path).display());
+            }
+            println!(
+                "\nNext: paste your input and the puzzle's example into the placeholders, add\n\
+                 both answers for each, and add a generator and a reference solver for the day.\n\
+                 Once it is solved, remove `scaffolded` and the ignore on its example tests."
+            );
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Where the inputs of the selected year are read from.
+struct Inputs {
+    year: u16,
+    locator: InputLocator,
+}
+
+impl Inputs {
+    fn path(&self, day: u8) -> PathBuf {
+        self.locator.path(self.year, day)
+    }
+
+    fn read(&self, day: u8) -> Result<String, InputError> {
+        self.locator.read(self.year, day)
+    }
+
+    fn answers_path(&self) -> PathBuf {
+        self.locator.answers_path(self.year)
+    }
+}
+
+fn select_solutions(year: u16, options: &Options) -> Result<Vec<&'static dyn DynSolution>, String> {
+    let solutions =
+        registry::solutions(year).ok_or_else(|| format!("no solutions registered for {}", year))?;
+    if options.days.is_empty() {
+        return Ok(solutions.to_vec());
+    }
+    options
+        .days
+        .iter()
+        .map(|&day| {
+            registry::find(year, day)
+                .ok_or_else(|| format!("no solution registered for day {} of {}", day, year))
+        })
+        .collect()
+}
+
+fn read_input(
+    solution: &dyn DynSolution,
+    inputs: &Inputs,
+    source: Option<&InputSource>,
+) -> Result<String, InputError> {
+    match source {
+        Some(InputSource::File(path)) => input::read_file(path),
+        Some(InputSource::Stdin) => input::read_stdin(),
+        None => inputs.read(solution.day()),
+    }
+}
+
+fn solve_input(
+    solution: &'static dyn DynSolution,
+    inputs: &Inputs,
+    options: &Options,
+) -> DayResult {
+    let input = read_input(solution, inputs, options.input.as_ref())?;
+    Ok(runner::solve_with_timeout(
+        solution,
+        input,
+        &options.parts(),
+        options.timeout,
+    )?)
+}
+
+/// Prints the answers of every day in the selected format. Returns whether
+/// every day was solved.
+fn print_days(solutions: &[&dyn DynSolution], results: &[DayResult], options: &Options) -> bool {
+    let mut passed = true;
+    let mut answers = Vec::new();
+    for (solution, result) in solutions.iter().zip(results) {
+        if options.format == Format::Text {
+            println!("\n{}", solution.info().heading());
+        }
+        let day_answers = match result {
+            Ok(day_answers) => day_answers,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                passed = false;
+                continue;
+            }
+        };
+        if options.format == Format::Text {
+            for answer in day_answers.answers.iter() {
+                println!("{} {}", answer.puzzle.question(answer.part), answer.answer);
+            }
+        }
+        for failure in day_answers.failures.iter() {
+            eprintln!("error: {}", failure);
+            passed = false;
+        }
+        answers.extend(day_answers.answers.iter().cloned());
+    }
+
+    let mut stdout = std::io::stdout().lock();
+    let written = match options.format {
+        Format::Text => Ok(()),
+        Format::Json => output::write_json(&mut stdout, &answers),
+        Format::Csv => output::write_csv(&mut stdout, &answers),
+    };
+    if let Err(e) = written {
+        eprintln!("error: {}", e);
+        passed = false;
+    }
+    passed
+}
+
+/// Compares the answers of every day against the expected answers file and
+/// lists the results. Returns whether every answer was correct.
+fn verify_days(
+    solutions: &[&dyn DynSolution],
+    results: &[DayResult],
+    inputs: &Inputs,
+    options: &Options,
+) -> bool {
+    let path = options
+        .answers
+        .clone()
+        .unwrap_or_else(|| inputs.answers_path());
+    let expected = match load_expected_answers(&path) {
+        Ok(expected) => expected,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+
+    let mut passed = true;
+    let mut correct = 0;
+    let mut total = 0;
+    let mut skipped = 0;
+    println!();
+    for (solution, result) in solutions.iter().zip(results) {
+        let day_answers = match result {
+            Ok(day_answers) => day_answers,
+            Err(e) => {
+                eprintln!("error: day {}: {}", solution.day(), e);
+                passed = false;
+                continue;
+            }
+        };
+        // A day without any expected answers, such as one just scaffolded,
+        // has nothing to verify yet.
+        let answers = if expected.has_day(solution.day()) {
+            day_answers.answers.clone()
+        } else {
+            skipped += 1;
+            println!(
+                "Day {:>2}: skipped, no expected answers{}",
+                solution.day(),
+                if solution.scaffolded() {
+                    " for a scaffolded day"
+                } else {
+                    ""
+                }
+            );
+            Vec::new()
+        };
+        for check in verify::check(&expected, answers) {
+            let answer = &check.answer;
+            total += 1;
+            match &check.outcome {
+                Outcome::Correct => {
+                    correct += 1;
+                    println!(
+                        "Day {:>2} part {}: ok",
+                        answer.puzzle.day,
+                        answer.part.number()
+                    );
+                }
+                Outcome::Mismatch { expected } => println!(
+                    "Day {:>2} part {}: MISMATCH expected {}, found {}",
+                    answer.puzzle.day,
+                    answer.part.number(),
+                    expected,
+                    answer.answer
+                ),
+                Outcome::Missing => println!(
+                    "Day {:>2} part {}: MISSING no expected answer, found {}",
+                    answer.puzzle.day,
+                    answer.part.number(),
+                    answer.answer
+                ),
+            }
+            passed &= check.passed();
+        }
+        for failure in day_answers.failures.iter() {
+            total += 1;
+            passed = false;
+            println!(
+                "Day {:>2} part {}: FAILED {}",
+                solution.day(),
+                failure.part.number(),
+                failure.error
+            );
+        }
+    }
+    print!("\n{} of {} answers verified", correct, total);
+    match skipped {
+        0 => println!(),
+        1 => println!(", 1 day skipped"),
+        _ => println!(", {} days skipped", skipped),
+    }
+    passed
+}
+
+/// Solves every selected day and writes the report. Returns whether every day
+/// was solved and the report written.
+fn write_report(
+    report: &Report,
+    solutions: &[&'static dyn DynSolution],
+    inputs: &Inputs,
+    options: &Options,
+) -> bool {
+    let results = parallel::map(solutions, options.jobs, |&solution| {
+        solve_input(solution, inputs, options)
+    });
+    let mut passed = true;
+    let days: Vec<DayReport> = solutions
+        .iter()
+        .zip(results)
+        .map(|(solution, result)| {
+            let result = result.map_err(|e| {
+                eprintln!("error: day {}: {}", solution.day(), e);
+                passed = false;
+                e.to_string()
+            });
+            for failure in result.iter().flat_map(|day_answers| &day_answers.failures) {
+                eprintln!("error: day {}: {}", solution.day(), failure);
+                passed = false;
+            }
+            DayReport {
+                puzzle: solution.info(),
+                result,
+            }
+        })
+        .collect();
+    let written = std::fs::File::create(&report.path).and_then(|file| {
+        let mut writer = std::io::BufWriter::new(file);
+        report::write(&mut writer, report.format, inputs.year, &days)?;
+        writer.flush()
+    });
+    match written {
+        Ok(()) => {
+            println!(
+                "Wrote a report of {} days to {}",
+                days.len(),
+                report.path.display()
+            );
+            passed
+        }
+        Err(e) => {
+            eprintln!("error: could not write {}: {}", report.path.display(), e);
+            false
+        }
+    }
+}
+
+/// Answers HTTP requests on localhost until the process is interrupted.
+/// Returns false if the port could not be bound.
+fn serve(port: u16, timeout: Duration) -> bool {
+    let listener = match server::bind(port) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("error: could not listen on port {}: {}", port, e);
+            return false;
+        }
+    };
+    if let Ok(address) = listener.local_addr() {
+        println!(
+            "Listening, POST an input to http://{}/<YEAR>/day/<DAY>/part/<PART>",
+            address
+        );
+    }
+    server::serve(&listener, timeout);
+    true
+}
+
+/// Parses the input of `solution` and hands it to the REPL. Returns whether
+/// the input could be read and parsed.
+fn explore_day(solution: &dyn DynSolution, inputs: &Inputs, options: &Options) -> bool {
+    let parsed = read_input(solution, inputs, options.input.as_ref())
+        .map_err(|e| e.to_string())
+        .and_then(|input| solution.parse(&input).map_err(|e| e.to_string()));
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+    println!("\n{}", solution.info().heading());
+    if let Err(e) = repl::run(
+        solution,
+        &*parsed,
+        std::io::stdin().lock(),
+        std::io::stdout().lock(),
+    ) {
+        eprintln!("error: {}", e);
+        return false;
+    }
+    true
+}
+
+/// Solves `solution` and then again every time its input file changes, printing
+/// the answers with their timings. Runs until the process is interrupted.
+fn watch_day(solution: &'static dyn DynSolution, inputs: &Inputs, options: &Options) -> ! {
+    let path = match &options.input {
+        Some(InputSource::File(path)) => path.clone(),
+        _ => inputs.path(solution.day()),
+    };
+    let mut watcher = Watcher::new(path);
+    println!(
+        "Watching {} for changes, press Ctrl-C to stop",
+        watcher.path().display()
+    );
+    loop {
+        println!("\n{}", solution.info().heading());
+        match solve_input(solution, inputs, options) {
+            Ok(day_answers) => {
+                println!(
+                    "Parsed in {}",
+                    bench::format_duration(day_answers.parse_duration)
+                );
+                for answer in day_answers.answers.iter() {
+                    println!(
+                        "{} {} ({})",
+                        answer.puzzle.question(answer.part),
+                        answer.answer,
+                        bench::format_duration(answer.duration)
+                    );
+                }
+                for failure in day_answers.failures.iter() {
+                    eprintln!("error: {}", failure);
+                }
+            }
+            Err(e) => eprintln!("error: {}", e),
+        }
+        watcher.wait(watch::POLL_INTERVAL);
+    }
+}
+
+fn load_expected_answers(path: &Path) -> Result<ExpectedAnswers, Box<dyn Error>> {
+    Ok(ExpectedAnswers::parse(&input::read_file(path)?)?)
+}
+
+/// Compares the wall-clock time of the whole run with the time each day took
+/// to parse and solve, added up. The days are timed by the clock on their own
+/// threads rather than by the CPU time they used, so on a machine with fewer
+/// CPUs than threads their time includes waiting for a CPU.
+fn print_parallel_summary(results: &[DayResult], wall_clock: Duration, options: &Options) {
+    let solved: Vec<&DayAnswers> = results.iter().flatten().collect();
+    let day_time = solved
+        .iter()
+        .map(|day_answers| day_answers.total_duration())
+        .sum::<Duration>();
+    let summary = format!(
+        "Solved {} days on {} threads in {} wall-clock, {} summed per-day parse and solve time",
+        solved.len(),
+        options.jobs,
+        bench::format_duration(wall_clock),
+        bench::format_duration(day_time),
+    );
+    if options.format == Format::Text {
+        println!("\n{}", summary);
+    } else {
+        eprintln!("{}", summary);
+    }
+}
+
+fn bench_days(solutions: &[&'static dyn DynSolution], inputs: &Inputs, options: &Options) -> bool {
+    let mut passed = true;
+    let mut results = Vec::new();
+    for &solution in solutions {
+        match bench_day(solution, inputs, options) {
+            Ok(result) => {
+                for failure in result.failures.iter() {
+                    eprintln!("error: day {}: {}", solution.day(), failure);
+                    passed = false;
+                }
+                results.push(result);
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                passed = false;
+            }
+        }
+    }
+    println!();
+    bench::print_table(&results);
+    passed
+}
+
+fn bench_day(
+    solution: &'static dyn DynSolution,
+    inputs: &Inputs,
+    options: &Options,
+) -> Result<bench::DayBench, Box<dyn Error>> {
+    let input = read_input(solution, inputs, options.input.as_ref())?;
+    Ok(bench::run_with_timeout(
+        solution,
+        input,
+        &options.parts(),
+        options.iterations,
+        options.memory,
+        options.timeout,
+    )?)
 }
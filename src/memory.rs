@@ -0,0 +1,165 @@
+//! Counting of the memory a solver allocates. The binary installs
+//! [`CountingAllocator`] as its global allocator, which only counts inside
+//! [`measure`] and only on the thread that called it.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// The system allocator, counting every allocation made on a thread that is
+/// inside [`measure`].
+pub struct CountingAllocator;
+
+struct Counters {
+    counting: Cell<bool>,
+    current: Cell<isize>,
+    peak: Cell<isize>,
+    total: Cell<usize>,
+    count: Cell<usize>,
+}
+
+thread_local! {
+    // Const initialized without a destructor, so using it never allocates.
+    static COUNTERS: Counters = const {
+        Counters {
+            counting: Cell::new(false),
+            current: Cell::new(0),
+            peak: Cell::new(0),
+            total: Cell::new(0),
+            count: Cell::new(0),
+        }
+    };
+}
+
+fn record_alloc(size: usize) {
+    let _ = COUNTERS.try_with(|counters| {
+        if counters.counting.get() {
+            let current = counters.current.get() + size as isize;
+            counters.current.set(current);
+            counters.peak.set(counters.peak.get().max(current));
+            counters.total.set(counters.total.get() + size);
+            counters.count.set(counters.count.get() + 1);
+        }
+    });
+}
+
+fn record_dealloc(size: usize) {
+    let _ = COUNTERS.try_with(|counters| {
+        if counters.counting.get() {
+            counters.current.set(counters.current.get() - size as isize);
+        }
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// What a stage allocated: the most bytes it held at once, the bytes of all
+/// its allocations together, and how many allocations it made. A reallocation
+/// counts as a new allocation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub peak: usize,
+    pub total: usize,
+    pub count: usize,
+}
+
+/// Runs `f` and returns what it allocated on this thread. Without
+/// [`CountingAllocator`] installed, the usage is always zero.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Usage) {
+    COUNTERS.with(|counters| {
+        counters.current.set(0);
+        counters.peak.set(0);
+        counters.total.set(0);
+        counters.count.set(0);
+        counters.counting.set(true);
+    });
+    let result = f();
+    let usage = COUNTERS.with(|counters| {
+        counters.counting.set(false);
+        Usage {
+            peak: counters.peak.get() as usize,
+            total: counters.total.get(),
+            count: counters.count.get(),
+        }
+    });
+    (result, usage)
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    if bytes < 1 << 10 {
+        format!("{}B", bytes)
+    } else if bytes < 1 << 20 {
+        format!("{:.2}KiB", bytes as f64 / (1 << 10) as f64)
+    } else if bytes < 1 << 30 {
+        format!("{:.2}MiB", bytes as f64 / (1 << 20) as f64)
+    } else {
+        format!("{:.2}GiB", bytes as f64 / (1 << 30) as f64)
+    }
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (_, usage) = measure(|| {
+            let small = vec![0u8; 100];
+            drop(small);
+            let mut large: Vec<u8> = Vec::with_capacity(1000);
+            large.extend(std::iter::repeat_n(1, 2000));
+            large.len()
+        });
+        // The small vector, the large one, and the large one grown to 2000.
+        assert_eq!(usage.count, 3);
+        assert_eq!(usage.total, 100 + 1000 + 2000);
+        assert_eq!(usage.peak, 2000);
+    }
+
+    #[test]
+    fn test_measure_ignores_other_threads() {
+        let (_, usage) = measure(|| std::thread::scope(|s| s.spawn(|| vec![0u8; 1 << 20]).join()));
+        assert!(usage.total < 1 << 20);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(3 << 20), "3.00MiB");
+        assert_eq!(format_bytes(5 << 30), "5.00GiB");
+    }
+}
@@ -0,0 +1,156 @@
+use crate::runner::Answer;
+use std::io::Write;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// Writes the answers as a JSON array with one object per day and part.
+/// Durations are in seconds.
+pub fn write_json(w: &mut impl Write, answers: &[Answer]) -> std::io::Result<()> {
+    writeln!(w, "[")?;
+    for (i, answer) in answers.iter().enumerate() {
+        writeln!(
+            w,
+            "  {{{}}}{}",
+            json_fields(answer),
+            if i + 1 < answers.len() { "," } else { "" },
+        )?;
+    }
+    writeln!(w, "]")
+}
+
+/// The members of the JSON object describing `answer`, without the braces so
+/// callers can add their own.
+pub(crate) fn json_fields(answer: &Answer) -> String {
+    let puzzle = answer.puzzle;
+    format!(
+        "\"year\": {}, \"day\": {}, \"part\": {}, \"title\": {}, \"question\": {}, \"url\": {}, \"answer\": {}, \"duration\": {:.9}",
+        puzzle.year,
+        puzzle.day,
+        answer.part.number(),
+        json_string(puzzle.title),
+        json_string(puzzle.question(answer.part)),
+        json_string(&puzzle.url()),
+        json_string(&answer.answer),
+        answer.duration.as_secs_f64(),
+    )
+}
+
+/// Writes the answers as CSV with a header row. Durations are in seconds.
+pub fn write_csv(w: &mut impl Write, answers: &[Answer]) -> std::io::Result<()> {
+    writeln!(w, "day,part,title,answer,duration")?;
+    for answer in answers {
+        writeln!(
+            w,
+            "{},{},{},{},{:.9}",
+            answer.puzzle.day,
+            answer.part.number(),
+            csv_field(answer.puzzle.title),
+            csv_field(&answer.answer),
+            answer.duration.as_secs_f64(),
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        String::from(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::PuzzleInfo;
+    use crate::solution::Part;
+    use std::time::Duration;
+
+    const DIVE: PuzzleInfo = PuzzleInfo {
+        year: 2021,
+        day: 2,
+        title: "Dive!",
+        questions: ["Where?", "Where, \"really\"?"],
+    };
+
+    fn answers() -> Vec<Answer> {
+        vec![
+            Answer {
+                puzzle: &DIVE,
+                part: Part::One,
+                answer: String::from("150"),
+                duration: Duration::from_micros(15),
+            },
+            Answer {
+                puzzle: &DIVE,
+                part: Part::Two,
+                answer: String::from("900"),
+                duration: Duration::from_millis(2),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut out = Vec::new();
+        write_json(&mut out, &answers()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[
+  {\"year\": 2021, \"day\": 2, \"part\": 1, \"title\": \"Dive!\", \"question\": \"Where?\", \"url\": \"https://adventofcode.com/2021/day/2\", \"answer\": \"150\", \"duration\": 0.000015000},
+  {\"year\": 2021, \"day\": 2, \"part\": 2, \"title\": \"Dive!\", \"question\": \"Where, \\\"really\\\"?\", \"url\": \"https://adventofcode.com/2021/day/2\", \"answer\": \"900\", \"duration\": 0.002000000}
+]
+"
+        );
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut out = Vec::new();
+        write_csv(&mut out, &answers()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day,part,title,answer,duration
+2,1,Dive!,150,0.000015000
+2,2,Dive!,900,0.002000000
+"
+        );
+    }
+
+    #[test]
+    fn test_json_string_escapes() {
+        assert_eq!(json_string("a \"b\"\\\n"), "\"a \\\"b\\\"\\\\\\n\"");
+    }
+
+    #[test]
+    fn test_csv_field_quotes() {
+        assert_eq!(
+            csv_field("The Treachery, of \"Whales\""),
+            "\"The Treachery, of \"\"Whales\"\"\""
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use crate::grid::Grid;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} input, line {}, column {}: {}, found ",
+            self.day, self.line, self.column, self.message
+        )?;
+        if self.text.is_empty() {
+            write!(f, "end of input")
+        } else {
+            write!(f, "{:?}", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// Creates an error for `text`, which must be a slice of `input`. The
+    /// line and column are worked out from where `text` sits in `input`.
+    pub(crate) fn new(day: u8, input: &str, text: &str, message: impl Into<String>) -> Self {
+        let (line, column) = position(input, text);
+        Self {
+            day,
+            line,
+            column,
+            text: String::from(text),
+            message: message.into(),
+        }
+    }
+
+    /// Re-anchors an error that was created against `fragment` so that its
+    /// position is relative to `input`, which `fragment` must be a slice of.
+    pub(crate) fn within(self, input: &str, fragment: &str) -> Self {
+        let (line, column) = position(input, fragment);
+        Self {
+            line: line + self.line - 1,
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+}
+
+/// Cleans up the quirks of saved input files before a day parses them: a UTF-8
+/// byte order mark, CRLF line endings, whitespace at the end of lines and
+/// blank lines at the end of the file. Whitespace at the start of and inside
+/// lines is kept, since some puzzles lay out their input with it. The result
+/// ends with a single line break unless it is empty.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = String::with_capacity(input.len() + 1);
+    for line in input.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    normalized.truncate(normalized.trim_end().len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Parses `token`, a slice of `input`, as a number. Errors say what was
+/// `expected`, for example "a crab position".
+pub(crate) fn number<T: FromStr>(
+    day: u8,
+    input: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(day, input, token, format!("expected {}", expected)))
+}
+
+/// Parses a list of numbers such as `3,4,3,1,2`, where `list` is a slice of
+/// `input`. Whitespace around each number is ignored.
+pub(crate) fn number_list<T: FromStr>(
+    day: u8,
+    input: &str,
+    list: &str,
+    separator: char,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    list.split(separator)
+        .map(|token| number(day, input, token.trim(), expected))
+        .collect()
+}
+
+/// Parses a map of single digits, one row per line.
+pub fn digit_grid(day: u8, input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(day, input, "a digit", |c| c.to_digit(10))
+}
+
+/// Splits `input` into blocks of lines separated by one or more blank lines.
+/// Every block is a slice of `input` without its trailing line break.
+pub fn blocks(input: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut block: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some((start, end)) = block.take() {
+                blocks.push(&input[start..end]);
+            }
+        } else {
+            let start = block.map_or(offset, |(start, _)| start);
+            block = Some((start, offset + content.len()));
+        }
+        offset += line.len();
+    }
+    if let Some((start, end)) = block {
+        blocks.push(&input[start..end]);
+    }
+    blocks
+}
+
+/// Splits a line such as `0,9 -> 5,9` around `separator`, trimming both
+/// sides. `line` must be a slice of `input`.
+pub(crate) fn pair<'a>(
+    day: u8,
+    input: &str,
+    line: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(separator)
+        .map(|(left, right)| (left.trim(), right.trim()))
+        .filter(|(left, right)| !left.is_empty() && !right.is_empty())
+        .ok_or_else(|| {
+            ParseError::new(
+                day,
+                input,
+                line,
+                format!("expected a line like a {} b", separator),
+            )
+        })
+}
+
+/// Returns the one-based line and column at which `text` starts in `input`.
+/// Panics if `text` is not a slice of `input`, since the position would be
+/// meaningless.
+fn position(input: &str, text: &str) -> (usize, usize) {
+    let start = input.as_ptr() as usize;
+    let text_start = text.as_ptr() as usize;
+    assert!(
+        text_start >= start && text_start + text.len() <= start + input.len(),
+        "text is not a slice of input"
+    );
+    let before = &input[..text_start - start];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1,2,3\n4,x,6\n";
+
+    #[test]
+    fn test_normalize_crlf() {
+        assert_eq!(normalize("1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3\n");
+    }
+
+    #[test]
+    fn test_normalize_bom() {
+        assert_eq!(normalize("\u{feff}199\n200\n"), "199\n200\n");
+    }
+
+    #[test]
+    fn test_normalize_trailing_blank_lines() {
+        assert_eq!(normalize("3,4,3,1,2\n\n\n"), "3,4,3,1,2\n");
+        assert_eq!(normalize("3,4,3,1,2"), "3,4,3,1,2\n");
+        assert_eq!(normalize("\n \n"), "");
+    }
+
+    #[test]
+    fn test_normalize_trailing_whitespace() {
+        assert_eq!(normalize("2199 \t\n3987  \r\n"), "2199\n3987\n");
+    }
+
+    #[test]
+    fn test_normalize_keeps_inner_whitespace() {
+        assert_eq!(normalize(" 8  2 23\n"), " 8  2 23\n");
+    }
+
+    #[test]
+    fn test_new_finds_line_and_column() {
+        let error = ParseError::new(6, INPUT, &INPUT[8..9], "expected a number");
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    #[should_panic(expected = "text is not a slice of input")]
+    fn test_new_rejects_text_outside_input() {
+        let other = String::from("x");
+        ParseError::new(6, INPUT, &other, "expected a number");
+    }
+
+    #[test]
+    fn test_within_offsets_position() {
+        let line = &INPUT[6..11];
+        let error = ParseError::new(6, line, &line[2..3], "expected a number").within(INPUT, line);
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn test_number() {
+        let error = number::<u8>(6, INPUT, &INPUT[8..9], "a timer").unwrap_err();
+        assert_eq!(error.message, "expected a timer");
+        assert_eq!(number::<u8>(6, INPUT, &INPUT[6..7], "a timer"), Ok(4));
+    }
+
+    #[test]
+    fn test_number_list() {
+        assert_eq!(
+            number_list::<u8>(6, INPUT, &INPUT[..5], ',', "a timer"),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            number_list::<u8>(7, " 16, 1 ,2", " 16, 1 ,2", ',', "a position"),
+            Ok(vec![16, 1, 2])
+        );
+    }
+
+    #[test]
+    fn test_number_list_error_position() {
+        let error = number_list::<u8>(6, INPUT, &INPUT[6..11], ',', "a timer").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn test_digit_grid() {
+        let grid = digit_grid(9, "219\n398").unwrap();
+        assert_eq!(grid[(1, 2)], 8);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "7,4,9\n\n22 13\n 8  2\n\n\n3 15\n";
+        assert_eq!(blocks(input), vec!["7,4,9", "22 13\n 8  2", "3 15"]);
+    }
+
+    #[test]
+    fn test_blocks_crlf() {
+        assert_eq!(blocks("1\r\n2\r\n\r\n3"), vec!["1\r\n2", "3"]);
+    }
+
+    #[test]
+    fn test_pair() {
+        let line = "0,9 -> 5,9";
+        assert_eq!(pair(5, line, line, "->"), Ok(("0,9", "5,9")));
+        assert!(pair(5, "0,9 5,9", "0,9 5,9", "->").is_err());
+        assert!(pair(5, "0,9 ->", "0,9 ->", "->").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(6, INPUT, &INPUT[8..9], "expected a number");
+        assert_eq!(
+            error.to_string(),
+            "day 6 input, line 2, column 3: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_display_end_of_input() {
+        let error = ParseError::new(6, INPUT, &INPUT[12..], "expected a number");
+        assert_eq!(
+            error.to_string(),
+            "day 6 input, line 3, column 1: expected a number, found end of input"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::solution::Part;
+
+/// What a puzzle is called and what it asks, as stated on its page. Headings,
+/// questions and links in every output are rendered from this.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PuzzleInfo {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub questions: [&'static str; 2],
+}
+
+impl PuzzleInfo {
+    pub fn question(&self, part: Part) -> &'static str {
+        self.questions[part.number() as usize - 1]
+    }
+
+    /// The puzzle's page on adventofcode.com.
+    pub fn url(&self) -> String {
+        format!("https://adventofcode.com/{}/day/{}", self.year, self.day)
+    }
+
+    /// The puzzle's title framed the way the site shows it.
+    pub fn heading(&self) -> String {
+        format!("--- Day {}: {} ---", self.day, self.title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INFO: PuzzleInfo = PuzzleInfo {
+        year: 2021,
+        day: 2,
+        title: "Dive!",
+        questions: ["Where to?", "Where to, really?"],
+    };
+
+    #[test]
+    fn test_question() {
+        assert_eq!(INFO.question(Part::Two), "Where to, really?");
+    }
+
+    #[test]
+    fn test_url() {
+        assert_eq!(INFO.url(), "https://adventofcode.com/2021/day/2");
+    }
+
+    #[test]
+    fn test_heading() {
+        assert_eq!(INFO.heading(), "--- Day 2: Dive! ---");
+    }
+}
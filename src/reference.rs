@@ -0,0 +1,535 @@
+//! Slow, straightforward reference solvers. They share no code with the day
+//! modules and skip their shortcuts, so tests can compare the two on generated
+//! inputs. Inputs are assumed to be well formed.
+
+use crate::y2021;
+use std::collections::{HashMap, HashSet};
+
+/// Solves both parts of `day` of `year` the slow way, or returns `None` if the
+/// day has no reference solver.
+pub fn solve(year: u16, day: u8, input: &str) -> Option<[String; 2]> {
+    if year != y2021::YEAR {
+        return None;
+    }
+    let answers = match day {
+        1 => strings(sonar_sweep(input)),
+        2 => strings(dive(input)),
+        3 => strings(binary_diagnostic(input)),
+        4 => strings(giant_squid(input)),
+        5 => strings(hydrothermal_venture(input)),
+        6 => strings(lanternfish(input)),
+        7 => strings(treachery_of_whales(input)),
+        8 => strings(seven_segment_search(input)),
+        9 => strings(smoke_basin(input)),
+        10 => strings(syntax_scoring(input)),
+        16 => strings(packet_decoder(input)),
+        _ => return None,
+    };
+    Some(answers)
+}
+
+fn strings<T: ToString>(answers: [T; 2]) -> [String; 2] {
+    answers.map(|answer| answer.to_string())
+}
+
+fn sonar_sweep(input: &str) -> [u64; 2] {
+    let depths: Vec<u32> = input.lines().map(|line| line.parse().unwrap()).collect();
+    let increases = |values: &[u32]| values.windows(2).filter(|w| w[0] < w[1]).count() as u64;
+    let sums: Vec<u32> = depths.windows(3).map(|w| w.iter().sum()).collect();
+    [increases(&depths), increases(&sums)]
+}
+
+fn dive(input: &str) -> [i64; 2] {
+    let (mut position, mut depth, mut aim) = (0, 0, 0);
+    for line in input.lines() {
+        let (command, value) = line.split_once(' ').unwrap();
+        let value: i64 = value.parse().unwrap();
+        match command {
+            "forward" => {
+                position += value;
+                depth += aim * value;
+            }
+            "down" => aim += value,
+            _ => aim -= value,
+        }
+    }
+    // In part one aim is exactly the depth.
+    [position * aim, position * depth]
+}
+
+fn binary_diagnostic(input: &str) -> [u64; 2] {
+    let report: Vec<&str> = input.lines().collect();
+    let width = report[0].len();
+    let ones = |report: &[&str], bit: usize| {
+        report
+            .iter()
+            .filter(|line| line.as_bytes()[bit] == b'1')
+            .count()
+    };
+    let mut gamma = String::new();
+    let mut epsilon = String::new();
+    for bit in 0..width {
+        let most_common_is_one = ones(&report, bit) * 2 > report.len();
+        gamma.push(if most_common_is_one { '1' } else { '0' });
+        epsilon.push(if most_common_is_one { '0' } else { '1' });
+    }
+
+    let rating = |most_common: bool| {
+        let mut candidates = report.clone();
+        for bit in 0..width {
+            if candidates.len() == 1 {
+                break;
+            }
+            let ones = ones(&candidates, bit);
+            let zeros = candidates.len() - ones;
+            let keep = if most_common {
+                if ones >= zeros {
+                    b'1'
+                } else {
+                    b'0'
+                }
+            } else if zeros <= ones {
+                b'0'
+            } else {
+                b'1'
+            };
+            if ones > 0 && zeros > 0 {
+                candidates.retain(|line| line.as_bytes()[bit] == keep);
+            }
+        }
+        u64::from_str_radix(candidates[0], 2).unwrap()
+    };
+    [
+        u64::from_str_radix(&gamma, 2).unwrap() * u64::from_str_radix(&epsilon, 2).unwrap(),
+        rating(true) * rating(false),
+    ]
+}
+
+fn giant_squid(input: &str) -> [u64; 2] {
+    let mut lines = input.lines();
+    let numbers: Vec<u64> = lines
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|n| n.parse().unwrap())
+        .collect();
+    let cells: Vec<u64> = lines
+        .flat_map(|line| line.split_whitespace())
+        .map(|n| n.parse().unwrap())
+        .collect();
+    let boards: Vec<&[u64]> = cells.chunks(25).collect();
+
+    let mut drawn = HashSet::new();
+    let mut winners = Vec::new();
+    for &number in numbers.iter() {
+        drawn.insert(number);
+        for (i, board) in boards.iter().enumerate() {
+            if winners.iter().any(|&(winner, _)| winner == i) {
+                continue;
+            }
+            let won = (0..5).any(|r| (0..5).all(|c| drawn.contains(&board[r * 5 + c])))
+                || (0..5).any(|c| (0..5).all(|r| drawn.contains(&board[r * 5 + c])));
+            if won {
+                let unmarked: u64 = board.iter().filter(|n| !drawn.contains(n)).sum();
+                winners.push((i, unmarked * number));
+            }
+        }
+    }
+    [winners[0].1, winners[winners.len() - 1].1]
+}
+
+fn hydrothermal_venture(input: &str) -> [usize; 2] {
+    let mut straight = HashMap::new();
+    let mut all = HashMap::new();
+    for line in input.lines() {
+        let coordinates: Vec<i32> = line
+            .split(" -> ")
+            .flat_map(|point| point.split(','))
+            .map(|n| n.parse().unwrap())
+            .collect();
+        let (x1, y1, x2, y2) = (
+            coordinates[0],
+            coordinates[1],
+            coordinates[2],
+            coordinates[3],
+        );
+        let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+        let steps = (x2 - x1).abs().max((y2 - y1).abs());
+        for step in 0..=steps {
+            let point = (x1 + dx * step, y1 + dy * step);
+            *all.entry(point).or_insert(0) += 1;
+            if dx == 0 || dy == 0 {
+                *straight.entry(point).or_insert(0) += 1;
+            }
+        }
+    }
+    let overlaps = |map: &HashMap<(i32, i32), u32>| map.values().filter(|&&n| n > 1).count();
+    [overlaps(&straight), overlaps(&all)]
+}
+
+fn lanternfish(input: &str) -> [u64; 2] {
+    let timers: Vec<u8> = input
+        .trim()
+        .split(',')
+        .map(|n| n.parse().unwrap())
+        .collect();
+
+    // Part one is small enough to follow every fish.
+    let mut fish = timers.clone();
+    for _ in 0..80 {
+        let mut babies = 0;
+        for timer in fish.iter_mut() {
+            if *timer == 0 {
+                *timer = 6;
+                babies += 1;
+            } else {
+                *timer -= 1;
+            }
+        }
+        fish.extend(std::iter::repeat_n(8, babies));
+    }
+
+    let mut counts = [0u64; 9];
+    for &timer in timers.iter() {
+        counts[timer as usize] += 1;
+    }
+    for _ in 0..256 {
+        let mut next = [0; 9];
+        next[..8].copy_from_slice(&counts[1..]);
+        next[6] += counts[0];
+        next[8] = counts[0];
+        counts = next;
+    }
+    [fish.len() as u64, counts.iter().sum()]
+}
+
+fn treachery_of_whales(input: &str) -> [i64; 2] {
+    let positions: Vec<i64> = input
+        .trim()
+        .split(',')
+        .map(|n| n.parse().unwrap())
+        .collect();
+    let (min, max) = (
+        *positions.iter().min().unwrap(),
+        *positions.iter().max().unwrap(),
+    );
+    let cheapest = |cost: fn(i64) -> i64| {
+        (min..=max)
+            .map(|target| positions.iter().map(|p| cost((p - target).abs())).sum())
+            .min()
+            .unwrap()
+    };
+    [cheapest(|d| d), cheapest(|d| d * (d + 1) / 2)]
+}
+
+const DIGIT_SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+fn seven_segment_search(input: &str) -> [u64; 2] {
+    let mut unique = 0;
+    let mut total = 0;
+    for line in input.lines() {
+        let (patterns, output) = line.split_once('|').unwrap();
+        let patterns: Vec<&str> = patterns.split_whitespace().collect();
+        let output: Vec<&str> = output.split_whitespace().collect();
+        unique += output
+            .iter()
+            .filter(|digit| [2, 3, 4, 7].contains(&digit.len()))
+            .count() as u64;
+
+        // Try every wiring until all ten patterns light up real digits.
+        let wiring = permutations(&['a', 'b', 'c', 'd', 'e', 'f', 'g'])
+            .into_iter()
+            .find(|wiring| {
+                patterns
+                    .iter()
+                    .all(|pattern| decode_digit(wiring, pattern).is_some())
+            })
+            .unwrap();
+        total += output.iter().fold(0, |acc, digit| {
+            acc * 10 + decode_digit(&wiring, digit).unwrap()
+        });
+    }
+    [unique, total]
+}
+
+/// Decodes a scrambled pattern, where `wiring[i]` is the wire that drives
+/// segment `a + i`.
+fn decode_digit(wiring: &[char], pattern: &str) -> Option<u64> {
+    let mut segments: Vec<char> = pattern
+        .chars()
+        .map(|wire| (b'a' + wiring.iter().position(|&w| w == wire).unwrap() as u8) as char)
+        .collect();
+    segments.sort();
+    let segments: String = segments.into_iter().collect();
+    DIGIT_SEGMENTS
+        .iter()
+        .position(|&digit| digit == segments)
+        .map(|digit| digit as u64)
+}
+
+fn permutations(items: &[char]) -> Vec<Vec<char>> {
+    if items.len() <= 1 {
+        return vec![items.to_vec()];
+    }
+    let mut result = Vec::new();
+    for i in 0..items.len() {
+        let mut rest = items.to_vec();
+        let first = rest.remove(i);
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, first);
+            result.push(permutation);
+        }
+    }
+    result
+}
+
+fn smoke_basin(input: &str) -> [u64; 2] {
+    let map: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect();
+    let neighbors = |r: usize, c: usize| {
+        let mut neighbors = Vec::new();
+        if r > 0 {
+            neighbors.push((r - 1, c));
+        }
+        if r + 1 < map.len() {
+            neighbors.push((r + 1, c));
+        }
+        if c > 0 {
+            neighbors.push((r, c - 1));
+        }
+        if c + 1 < map[r].len() {
+            neighbors.push((r, c + 1));
+        }
+        neighbors
+    };
+
+    let mut risk = 0;
+    let mut basins = Vec::new();
+    for r in 0..map.len() {
+        for c in 0..map[r].len() {
+            if neighbors(r, c)
+                .iter()
+                .any(|&(nr, nc)| map[nr][nc] <= map[r][c])
+            {
+                continue;
+            }
+            risk += map[r][c] as u64 + 1;
+
+            let mut seen = HashSet::new();
+            let mut stack = vec![(r, c)];
+            while let Some((r, c)) = stack.pop() {
+                if map[r][c] == 9 || !seen.insert((r, c)) {
+                    continue;
+                }
+                stack.extend(neighbors(r, c));
+            }
+            basins.push(seen.len() as u64);
+        }
+    }
+    basins.sort_by(|a, b| b.cmp(a));
+    [risk, basins.iter().take(3).product()]
+}
+
+fn syntax_scoring(input: &str) -> [u64; 2] {
+    let mut error_score = 0;
+    let mut completion_scores = Vec::new();
+    for line in input.lines() {
+        // Strip matching pairs until none are left.
+        let mut line = line.to_string();
+        loop {
+            let shorter = line
+                .replace("()", "")
+                .replace("[]", "")
+                .replace("{}", "")
+                .replace("<>", "");
+            if shorter.len() == line.len() {
+                break;
+            }
+            line = shorter;
+        }
+        match line.chars().find(|c| ")]}>".contains(*c)) {
+            Some(c) => {
+                error_score += match c {
+                    ')' => 3,
+                    ']' => 57,
+                    '}' => 1197,
+                    _ => 25137,
+                }
+            }
+            None => completion_scores.push(
+                line.chars()
+                    .rev()
+                    .fold(0, |acc, c| acc * 5 + "([{<".find(c).unwrap() as u64 + 1),
+            ),
+        }
+    }
+    completion_scores.sort();
+    [error_score, completion_scores[completion_scores.len() / 2]]
+}
+
+fn packet_decoder(input: &str) -> [u64; 2] {
+    let bits: Vec<u64> = input
+        .trim()
+        .chars()
+        .flat_map(|c| {
+            let value = c.to_digit(16).unwrap() as u64;
+            (0..4).rev().map(move |i| (value >> i) & 1)
+        })
+        .collect();
+    let mut cursor = 0;
+    let (version_sum, value) = decode_packet(&bits, &mut cursor);
+    [version_sum, value]
+}
+
+/// Decodes the packet at `cursor`, returning its version sum and value.
+fn decode_packet(bits: &[u64], cursor: &mut usize) -> (u64, u64) {
+    let read = |cursor: &mut usize, len: usize| {
+        let value = bits[*cursor..*cursor + len]
+            .iter()
+            .fold(0, |acc, bit| acc * 2 + bit);
+        *cursor += len;
+        value
+    };
+    let mut version_sum = read(cursor, 3);
+    let type_id = read(cursor, 3);
+    if type_id == 4 {
+        let mut value = 0;
+        loop {
+            let group = read(cursor, 5);
+            value = value * 16 + (group & 0xf);
+            if group < 16 {
+                return (version_sum, value);
+            }
+        }
+    }
+
+    let mut values = Vec::new();
+    if read(cursor, 1) == 0 {
+        let end = read(cursor, 15) as usize + *cursor;
+        while *cursor < end {
+            let (versions, value) = decode_packet(bits, cursor);
+            version_sum += versions;
+            values.push(value);
+        }
+    } else {
+        for _ in 0..read(cursor, 11) {
+            let (versions, value) = decode_packet(bits, cursor);
+            version_sum += versions;
+            values.push(value);
+        }
+    }
+    let value = match type_id {
+        0 => values.iter().sum(),
+        1 => values.iter().product(),
+        2 => *values.iter().min().unwrap(),
+        3 => *values.iter().max().unwrap(),
+        5 => (values[0] > values[1]) as u64,
+        6 => (values[0] < values[1]) as u64,
+        _ => (values[0] == values[1]) as u64,
+    };
+    (version_sum, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::{self, Rng};
+    use crate::registry;
+    use crate::runner;
+    use crate::solution::Part;
+
+    /// Solves generated inputs for `day` with both the registered solver and
+    /// the reference solver, and checks that every answer agrees.
+    fn assert_matches_reference(day: u8, size: usize, seeds: std::ops::Range<u64>) {
+        let solution = registry::find(2021, day).unwrap();
+        for seed in seeds {
+            let input = generate::generate(2021, day, &mut Rng::new(seed), size).unwrap();
+            let answers = runner::solve(solution, &input, &Part::ALL).unwrap().answers;
+            let expected = solve(2021, day, &input).unwrap();
+            for (answer, expected) in answers.iter().zip(expected) {
+                assert_eq!(
+                    answer.answer,
+                    expected,
+                    "day {} part {} with seed {} and size {}",
+                    day,
+                    answer.part.number(),
+                    seed,
+                    size
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_registered_day_has_a_reference() {
+        for solution in y2021::SOLUTIONS.iter().filter(|s| !s.scaffolded()) {
+            let input = generate::generate(2021, solution.day(), &mut Rng::new(0), 10)
+                .unwrap_or_else(|e| panic!("{}", e));
+            assert!(
+                solve(2021, solution.day(), &input).is_some(),
+                "no reference solver for day {}",
+                solution.day()
+            );
+        }
+    }
+
+    #[test]
+    fn test_sonar_matches_reference() {
+        assert_matches_reference(1, 500, 0..10);
+    }
+
+    #[test]
+    fn test_course_matches_reference() {
+        assert_matches_reference(2, 500, 0..10);
+    }
+
+    #[test]
+    fn test_diagnostic_report_matches_reference() {
+        assert_matches_reference(3, 300, 0..10);
+    }
+
+    #[test]
+    fn test_bingo_matches_reference() {
+        assert_matches_reference(4, 30, 0..10);
+    }
+
+    #[test]
+    fn test_vent_map_matches_reference() {
+        assert_matches_reference(5, 100, 0..10);
+    }
+
+    #[test]
+    fn test_population_matches_reference() {
+        assert_matches_reference(6, 20, 0..10);
+    }
+
+    #[test]
+    fn test_crab_fleet_matches_reference() {
+        assert_matches_reference(7, 200, 0..10);
+        assert_matches_reference(7, 3, 0..50);
+    }
+
+    #[test]
+    fn test_display_matches_reference() {
+        assert_matches_reference(8, 20, 0..5);
+    }
+
+    #[test]
+    fn test_height_map_matches_reference() {
+        assert_matches_reference(9, 30, 0..10);
+    }
+
+    #[test]
+    fn test_syntax_checker_matches_reference() {
+        assert_matches_reference(10, 50, 0..10);
+    }
+
+    #[test]
+    fn test_decoder_matches_reference() {
+        assert_matches_reference(16, 100, 0..10);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::solution::DynSolution;
+use crate::y2021;
+
+/// The solutions registered for one year of puzzles.
+pub struct Year {
+    pub year: u16,
+    pub solutions: &'static [&'static dyn DynSolution],
+}
+
+/// Every supported year, oldest first.
+pub static YEARS: &[Year] = &[Year {
+    year: y2021::YEAR,
+    solutions: y2021::SOLUTIONS,
+}];
+
+/// The most recent year, which is used when none is selected.
+pub fn latest_year() -> u16 {
+    YEARS.last().map(|year| year.year).unwrap_or(y2021::YEAR)
+}
+
+pub fn solutions(year: u16) -> Option<&'static [&'static dyn DynSolution]> {
+    YEARS
+        .iter()
+        .find(|registered| registered.year == year)
+        .map(|registered| registered.solutions)
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn DynSolution> {
+    solutions(year)?
+        .iter()
+        .copied()
+        .find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(
+            find(2021, 9).map(|solution| solution.title()),
+            Some("Smoke Basin")
+        );
+        assert!(find(2021, 25).is_none());
+        assert!(find(2015, 1).is_none());
+    }
+
+    #[test]
+    fn test_years_are_unique_and_ordered() {
+        assert!(YEARS.windows(2).all(|pair| pair[0].year < pair[1].year));
+        assert_eq!(latest_year(), YEARS[YEARS.len() - 1].year);
+    }
+}
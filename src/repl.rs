@@ -0,0 +1,143 @@
+use advent_of_code_2021::runner;
+use advent_of_code_2021::solution::{DynSolution, Part};
+use std::any::Any;
+use std::io::{self, BufRead, Write};
+use std::panic::AssertUnwindSafe;
+
+const GENERAL_COMMANDS: [(&str, &str); 3] = [
+    ("part 1|2", "solve a part"),
+    ("help", "list the commands"),
+    ("quit", "leave the REPL"),
+];
+
+/// Reads commands for `solution` from `reader` until it runs out or the user
+/// quits, and writes their results to `writer`. `input` is the parsed input
+/// the commands inspect. A command that panics is reported as an error and
+/// the session carries on.
+pub fn run(
+    solution: &dyn DynSolution,
+    input: &dyn Any,
+    reader: impl BufRead,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    let prompt = format!("day {}> ", solution.day());
+    writeln!(writer, "Type help for a list of commands")?;
+    write!(writer, "{}", prompt)?;
+    writer.flush()?;
+    for line in reader.lines() {
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.split_first() {
+            None => {}
+            Some((&"quit" | &"exit", _)) => return Ok(()),
+            Some((&"help", _)) => write_help(solution, &mut writer)?,
+            Some((command, args)) => {
+                let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    execute(solution, input, command, args)
+                }));
+                match result {
+                    Ok(Ok(output)) => writeln!(writer, "{}", output)?,
+                    Ok(Err(e)) => writeln!(writer, "error: {}", e)?,
+                    Err(payload) => writeln!(
+                        writer,
+                        "error: command panicked: {}",
+                        runner::panic_message(&*payload)
+                    )?,
+                }
+            }
+        }
+        write!(writer, "{}", prompt)?;
+        writer.flush()?;
+    }
+    writeln!(writer)
+}
+
+/// Solves a part or runs one of the day's inspection commands.
+fn execute(
+    solution: &dyn DynSolution,
+    input: &dyn Any,
+    command: &str,
+    args: &[&str],
+) -> Result<String, String> {
+    match (command, args) {
+        ("part", ["1"]) => Ok(solution.solve(Part::One, input)),
+        ("part", ["2"]) => Ok(solution.solve(Part::Two, input)),
+        ("part", _) => Err(String::from("usage: part 1|2")),
+        _ => solution
+            .inspect(input, command, args)
+            .map_err(|e| e.to_string()),
+    }
+}
+
+fn write_help(solution: &dyn DynSolution, writer: &mut impl Write) -> io::Result<()> {
+    let commands = solution
+        .commands()
+        .iter()
+        .map(|command| (command.usage, command.description));
+    for (usage, description) in commands.chain(GENERAL_COMMANDS) {
+        writeln!(writer, "  {:<28}  {}", usage, description)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SeaCucumber;
+    use advent_of_code_2021::y2021::day_06::Day06;
+
+    fn session(commands: &str) -> String {
+        let input = DynSolution::parse(&Day06, "3,4,3,1,2").unwrap();
+        let mut output = Vec::new();
+        run(&Day06, &*input, commands.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_inspect_command() {
+        assert_eq!(
+            session("simulate 18\n"),
+            "Type help for a list of commands\nday 6> 26\nday 6> \n"
+        );
+    }
+
+    #[test]
+    fn test_part_command() {
+        assert!(session("part 1\nquit\n").contains("day 6> 5934\nday 6> "));
+    }
+
+    #[test]
+    fn test_unknown_command() {
+        assert!(session("spawn\n").contains("error: unknown command, try help"));
+    }
+
+    #[test]
+    fn test_panicking_commands() {
+        let mut output = Vec::new();
+        let broken = SeaCucumber {
+            delay_ms: [0, 0],
+            panics: [None, Some("no herd of sea cucumbers")],
+        };
+        run(
+            &broken,
+            &(),
+            "part 2\nherd\npart 1\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Type help for a list of commands\n\
+             day 25> error: command panicked: no herd of sea cucumbers\n\
+             day 25> error: command panicked: no herd to inspect\n\
+             day 25> 1\nday 25> \n"
+        );
+    }
+
+    #[test]
+    fn test_help_lists_day_commands() {
+        let output = session("help\n");
+        assert!(output.contains("simulate DAYS"));
+        assert!(output.contains("part 1|2"));
+    }
+}
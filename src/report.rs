@@ -0,0 +1,323 @@
+//! Summaries of a whole year, with every day's answers and timings, for
+//! reading outside the terminal.
+
+use crate::bench::format_duration;
+use crate::puzzle::PuzzleInfo;
+use crate::runner::{DayAnswers, PartFailure};
+use crate::solution::Part;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    /// Picks the format from the extension of `path`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "md" | "markdown" => Some(ReportFormat::Markdown),
+            "html" | "htm" => Some(ReportFormat::Html),
+            _ => None,
+        }
+    }
+}
+
+/// A day of the report: its puzzle, and its answers or why it has none.
+pub struct DayReport {
+    pub puzzle: &'static PuzzleInfo,
+    pub result: Result<DayAnswers, String>,
+}
+
+impl DayReport {
+    fn answer(&self, part: Part) -> Option<&str> {
+        let day_answers = self.result.as_ref().ok()?;
+        day_answers
+            .answers
+            .iter()
+            .find(|answer| answer.part == part)
+            .map(|answer| answer.answer.as_str())
+    }
+
+    fn failures(&self) -> &[PartFailure] {
+        self.result
+            .as_ref()
+            .map_or(&[], |day_answers| &day_answers.failures)
+    }
+
+    fn parse_duration(&self) -> Option<Duration> {
+        Some(self.result.as_ref().ok()?.parse_duration)
+    }
+
+    fn solve_duration(&self) -> Option<Duration> {
+        let day_answers = self.result.as_ref().ok()?;
+        Some(
+            day_answers
+                .answers
+                .iter()
+                .map(|answer| answer.duration)
+                .sum(),
+        )
+    }
+}
+
+pub fn write(
+    w: &mut impl Write,
+    format: ReportFormat,
+    year: u16,
+    days: &[DayReport],
+) -> std::io::Result<()> {
+    match format {
+        ReportFormat::Markdown => write_markdown(w, year, days),
+        ReportFormat::Html => write_html(w, year, days),
+    }
+}
+
+/// Writes a table with a row per day, then a section per day with its
+/// questions and answers.
+pub fn write_markdown(w: &mut impl Write, year: u16, days: &[DayReport]) -> std::io::Result<()> {
+    writeln!(w, "# Advent of Code {}\n", year)?;
+    writeln!(w, "| Day | Title | Part 1 | Part 2 | Parse | Solve |")?;
+    writeln!(w, "| ---: | --- | --- | --- | ---: | ---: |")?;
+    for day in days {
+        writeln!(
+            w,
+            "| {} | [{}]({}) | {} | {} | {} | {} |",
+            day.puzzle.day,
+            markdown_cell(day.puzzle.title),
+            day.puzzle.url(),
+            markdown_cell(day.answer(Part::One).unwrap_or("")),
+            markdown_cell(day.answer(Part::Two).unwrap_or("")),
+            day.parse_duration()
+                .map(format_duration)
+                .unwrap_or_default(),
+            day.solve_duration()
+                .map(format_duration)
+                .unwrap_or_default(),
+        )?;
+    }
+    for day in days {
+        writeln!(w, "\n## Day {}: {}\n", day.puzzle.day, day.puzzle.title)?;
+        if let Err(e) = &day.result {
+            writeln!(w, "Not solved: {}\n", e)?;
+        }
+        for failure in day.failures() {
+            writeln!(w, "Not solved: {}\n", failure)?;
+        }
+        for part in Part::ALL {
+            write!(w, "{}. {}", part.number(), day.puzzle.question(part))?;
+            match day.answer(part) {
+                Some(answer) => writeln!(w, " **{}**", answer)?,
+                None => writeln!(w)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes a standalone page with the same table and sections as
+/// [`write_markdown`].
+pub fn write_html(w: &mut impl Write, year: u16, days: &[DayReport]) -> std::io::Result<()> {
+    writeln!(w, "<!DOCTYPE html>")?;
+    writeln!(w, "<html lang=\"en\">")?;
+    writeln!(w, "<head>")?;
+    writeln!(w, "<meta charset=\"utf-8\">")?;
+    writeln!(w, "<title>Advent of Code {}</title>", year)?;
+    writeln!(w, "</head>")?;
+    writeln!(w, "<body>")?;
+    writeln!(w, "<h1>Advent of Code {}</h1>", year)?;
+    writeln!(w, "<table>")?;
+    writeln!(
+        w,
+        "<tr><th>Day</th><th>Title</th><th>Part 1</th><th>Part 2</th><th>Parse</th><th>Solve</th></tr>"
+    )?;
+    for day in days {
+        writeln!(
+            w,
+            "<tr><td>{}</td><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            day.puzzle.day,
+            day.puzzle.url(),
+            html_escape(day.puzzle.title),
+            html_escape(day.answer(Part::One).unwrap_or("")),
+            html_escape(day.answer(Part::Two).unwrap_or("")),
+            day.parse_duration().map(format_duration).unwrap_or_default(),
+            day.solve_duration().map(format_duration).unwrap_or_default(),
+        )?;
+    }
+    writeln!(w, "</table>")?;
+    for day in days {
+        writeln!(w, "<section>")?;
+        writeln!(
+            w,
+            "<h2>Day {}: {}</h2>",
+            day.puzzle.day,
+            html_escape(day.puzzle.title)
+        )?;
+        if let Err(e) = &day.result {
+            writeln!(w, "<p>Not solved: {}</p>", html_escape(e))?;
+        }
+        for failure in day.failures() {
+            writeln!(
+                w,
+                "<p>Not solved: {}</p>",
+                html_escape(&failure.to_string())
+            )?;
+        }
+        writeln!(w, "<ol>")?;
+        for part in Part::ALL {
+            write!(w, "<li>{}", html_escape(day.puzzle.question(part)))?;
+            if let Some(answer) = day.answer(part) {
+                write!(w, " <strong>{}</strong>", html_escape(answer))?;
+            }
+            writeln!(w, "</li>")?;
+        }
+        writeln!(w, "</ol>")?;
+        writeln!(w, "</section>")?;
+    }
+    writeln!(w, "</body>")?;
+    writeln!(w, "</html>")
+}
+
+fn markdown_cell(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+fn html_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{Answer, SolveError};
+
+    const DIVE: PuzzleInfo = PuzzleInfo {
+        year: 2021,
+        day: 2,
+        title: "Dive!",
+        questions: ["Where?", "Where <really>?"],
+    };
+
+    const SQUID: PuzzleInfo = PuzzleInfo {
+        year: 2021,
+        day: 4,
+        title: "Giant Squid",
+        questions: ["First?", "Last?"],
+    };
+
+    fn days() -> Vec<DayReport> {
+        let answer = |part, answer: &str, micros| Answer {
+            puzzle: &DIVE,
+            part,
+            answer: String::from(answer),
+            duration: Duration::from_micros(micros),
+        };
+        vec![
+            DayReport {
+                puzzle: &DIVE,
+                result: Ok(DayAnswers {
+                    day: 2,
+                    parse_duration: Duration::from_micros(5),
+                    answers: vec![answer(Part::One, "150", 10), answer(Part::Two, "900", 20)],
+                    failures: Vec::new(),
+                }),
+            },
+            DayReport {
+                puzzle: &SQUID,
+                result: Err(String::from("no input")),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            ReportFormat::from_path(Path::new("weekly.md")),
+            Some(ReportFormat::Markdown)
+        );
+        assert_eq!(
+            ReportFormat::from_path(Path::new("out/weekly.html")),
+            Some(ReportFormat::Html)
+        );
+        assert_eq!(ReportFormat::from_path(Path::new("weekly.txt")), None);
+        assert_eq!(ReportFormat::from_path(Path::new("weekly")), None);
+    }
+
+    #[test]
+    fn test_write_markdown() {
+        let mut out = Vec::new();
+        write_markdown(&mut out, 2021, &days()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "# Advent of Code 2021
+
+| Day | Title | Part 1 | Part 2 | Parse | Solve |
+| ---: | --- | --- | --- | ---: | ---: |
+| 2 | [Dive!](https://adventofcode.com/2021/day/2) | 150 | 900 | 5.00µs | 30.00µs |
+| 4 | [Giant Squid](https://adventofcode.com/2021/day/4) |  |  |  |  |
+
+## Day 2: Dive!
+
+1. Where? **150**
+2. Where <really>? **900**
+
+## Day 4: Giant Squid
+
+Not solved: no input
+
+1. First?
+2. Last?
+"
+        );
+    }
+
+    #[test]
+    fn test_write_markdown_with_a_failed_part() {
+        let mut days = days();
+        let day_answers = days[0].result.as_mut().unwrap();
+        day_answers.answers.pop();
+        day_answers.failures.push(PartFailure {
+            part: Part::Two,
+            error: SolveError::Panicked(String::from("no submarine")),
+        });
+        let mut out = Vec::new();
+        write_markdown(&mut out, 2021, &days).unwrap();
+        let markdown = String::from_utf8(out).unwrap();
+        assert!(markdown.contains("| 150 |  | 5.00µs | 10.00µs |"));
+        assert!(markdown.contains(
+            "Not solved: part 2: solver panicked: no submarine\n\n1. Where? **150**\n2. Where <really>?\n"
+        ));
+    }
+
+    #[test]
+    fn test_write_html() {
+        let mut out = Vec::new();
+        write_html(&mut out, 2021, &days()).unwrap();
+        let html = String::from_utf8(out).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.contains(
+            "<tr><td>2</td><td><a href=\"https://adventofcode.com/2021/day/2\">Dive!</a></td><td>150</td><td>900</td><td>5.00µs</td><td>30.00µs</td></tr>"
+        ));
+        assert!(html.contains("<li>Where &lt;really&gt;? <strong>900</strong></li>"));
+        assert!(html.contains("<p>Not solved: no input</p>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn test_markdown_cell_escapes_pipes() {
+        assert_eq!(markdown_cell("a|b"), "a\\|b");
+    }
+}
@@ -0,0 +1,455 @@
+use crate::bench::format_duration;
+use crate::parse::ParseError;
+use crate::puzzle::PuzzleInfo;
+use crate::solution::{DynSolution, Part};
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Once};
+use std::time::{Duration, Instant};
+
+/// How long a day may take by default. Every puzzle has a solution that runs
+/// in about this long on old hardware, so a day taking longer is stuck.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub puzzle: &'static PuzzleInfo,
+    pub part: Part,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+/// The answers of a day, and the parts that could not be answered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayAnswers {
+    pub day: u8,
+    pub parse_duration: Duration,
+    pub answers: Vec<Answer>,
+    pub failures: Vec<PartFailure>,
+}
+
+impl DayAnswers {
+    /// Time spent parsing and solving, leaving out the time around them,
+    /// such as reading the input and starting the solver thread.
+    pub fn total_duration(&self) -> Duration {
+        self.parse_duration
+            + self
+                .answers
+                .iter()
+                .map(|answer| answer.duration)
+                .sum::<Duration>()
+    }
+}
+
+/// Parses `input` once and solves each of `parts`, timing the parse and every
+/// part.
+pub fn solve(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+) -> Result<DayAnswers, ParseError> {
+    let start = Instant::now();
+    let parsed = solution.parse(input)?;
+    let parse_duration = start.elapsed();
+    let answers = parts
+        .iter()
+        .map(|&part| solve_part(solution, part, &*parsed))
+        .collect();
+    Ok(DayAnswers {
+        day: solution.day(),
+        parse_duration,
+        answers,
+        failures: Vec::new(),
+    })
+}
+
+fn solve_part(solution: &dyn DynSolution, part: Part, parsed: &dyn Any) -> Answer {
+    let start = Instant::now();
+    let answer = solution.solve(part, parsed);
+    Answer {
+        puzzle: solution.info(),
+        part,
+        answer,
+        duration: start.elapsed(),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    TimedOut(Duration),
+    Panicked(String),
+    /// Too many solvers that ran out of time are still running.
+    Overloaded(usize),
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::TimedOut(timeout) => {
+                write!(f, "gave up after {}", format_duration(*timeout))
+            }
+            SolveError::Panicked(message) => write!(f, "solver panicked: {}", message),
+            SolveError::Overloaded(running) => write!(
+                f,
+                "refused, {} solvers that ran out of time are still running",
+                running
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// A part that panicked or ran out of time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartFailure {
+    pub part: Part,
+    pub error: SolveError,
+}
+
+impl std::fmt::Display for PartFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "part {}: {}", self.part.number(), self.error)
+    }
+}
+
+/// Like [`solve`], but on a thread of its own that is given `timeout` to parse
+/// and solve every part. A part that panics or runs out of time is listed in
+/// the failures, and the parts before it keep their answers. A thread that
+/// runs out of time cannot be stopped, so it is left running in the
+/// background while the caller moves on. While [`MAX_ABANDONED_SOLVERS`] of
+/// those are running, new days are refused with [`SolveError::Overloaded`].
+pub fn solve_with_timeout(
+    solution: &'static dyn DynSolution,
+    input: String,
+    parts: &[Part],
+    timeout: Duration,
+) -> Result<DayAnswers, SolveError> {
+    let deadline = Instant::now() + timeout;
+    let (parse_sender, parse_receiver) = mpsc::channel();
+    let (part_sender, part_receiver) = mpsc::channel();
+    let thread_parts = parts.to_vec();
+    let solver = spawn_solver(solution.day(), move || {
+        let start = Instant::now();
+        let parsed = match std::panic::catch_unwind(AssertUnwindSafe(|| solution.parse(&input))) {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(e)) => {
+                let _ = parse_sender.send(Err(SolveError::Parse(e)));
+                return;
+            }
+            Err(payload) => {
+                let _ = parse_sender.send(Err(SolveError::Panicked(panic_message(&*payload))));
+                return;
+            }
+        };
+        let _ = parse_sender.send(Ok(start.elapsed()));
+        for part in thread_parts {
+            let answer =
+                std::panic::catch_unwind(AssertUnwindSafe(|| solve_part(solution, part, &*parsed)))
+                    .map_err(|payload| SolveError::Panicked(panic_message(&*payload)));
+            let _ = part_sender.send(answer);
+        }
+    })?;
+
+    let receive_error = |error| match error {
+        RecvTimeoutError::Timeout => SolveError::TimedOut(timeout),
+        RecvTimeoutError::Disconnected => {
+            SolveError::Panicked(String::from("the solver thread exited without an answer"))
+        }
+    };
+    let parse_duration = parse_receiver
+        .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+        .map_err(|error| {
+            if error == RecvTimeoutError::Timeout {
+                solver.abandon();
+            }
+            receive_error(error)
+        })??;
+    let mut answers = Vec::new();
+    let mut failures = Vec::new();
+    let mut timed_out = false;
+    for &part in parts {
+        // Once a part runs out of time, the ones after it never start.
+        let result = if timed_out {
+            Err(SolveError::TimedOut(timeout))
+        } else {
+            part_receiver
+                .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                .map_err(receive_error)
+                .and_then(|answer| answer)
+        };
+        match result {
+            Ok(answer) => answers.push(answer),
+            Err(error) => {
+                if !timed_out && matches!(error, SolveError::TimedOut(_)) {
+                    timed_out = true;
+                    solver.abandon();
+                }
+                failures.push(PartFailure { part, error });
+            }
+        }
+    }
+    Ok(DayAnswers {
+        day: solution.day(),
+        parse_duration,
+        answers,
+        failures,
+    })
+}
+
+/// Runs `f` on a thread of its own for `day`, and gives it `timeout` to
+/// finish. Like [`solve_with_timeout`], a thread that runs out of time is left
+/// running in the background and counts towards the limit.
+pub fn run_with_timeout<T: Send + 'static>(
+    day: u8,
+    timeout: Duration,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, SolveError> {
+    let (sender, receiver) = mpsc::channel();
+    let solver = spawn_solver(day, move || {
+        let _ = sender.send(std::panic::catch_unwind(AssertUnwindSafe(f)));
+    })?;
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(payload)) => Err(SolveError::Panicked(panic_message(&*payload))),
+        Err(RecvTimeoutError::Timeout) => {
+            solver.abandon();
+            Err(SolveError::TimedOut(timeout))
+        }
+        Err(RecvTimeoutError::Disconnected) => Err(SolveError::Panicked(String::from(
+            "the solver thread exited without an answer",
+        ))),
+    }
+}
+
+const SOLVER_THREAD_PREFIX: &str = "day ";
+
+/// Solver threads that ran out of time and are still running.
+static ABANDONED: AtomicUsize = AtomicUsize::new(0);
+
+/// How many solver threads that ran out of time are still running.
+pub fn abandoned_solvers() -> usize {
+    ABANDONED.load(Ordering::SeqCst)
+}
+
+/// How many abandoned solvers may run before new ones are refused, since each
+/// of them may keep a CPU busy for as long as the process lives.
+pub const MAX_ABANDONED_SOLVERS: usize = 8;
+
+/// Whether a solver thread finished or was abandoned first, keeping `running`
+/// at the number of abandoned threads that have not finished.
+#[derive(Clone)]
+struct Solver {
+    ended: Arc<AtomicBool>,
+    running: &'static AtomicUsize,
+}
+
+impl Solver {
+    fn new(running: &'static AtomicUsize) -> Self {
+        Self {
+            ended: Arc::new(AtomicBool::new(false)),
+            running,
+        }
+    }
+
+    /// Called by the caller that stops waiting for the thread.
+    fn abandon(&self) {
+        self.running.fetch_add(1, Ordering::SeqCst);
+        if self.ended.swap(true, Ordering::SeqCst) {
+            self.running.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Called by the thread once it is done.
+    fn finish(&self) {
+        if self.ended.swap(true, Ordering::SeqCst) {
+            self.running.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Spawns a solver thread for `day`, unless too many abandoned solvers are
+/// still running. Its panics are caught and reported as a [`SolveError`], so
+/// the panic hook is told to keep quiet about them.
+fn spawn_solver(day: u8, f: impl FnOnce() + Send + 'static) -> Result<Solver, SolveError> {
+    let running = abandoned_solvers();
+    if running >= MAX_ABANDONED_SOLVERS {
+        return Err(SolveError::Overloaded(running));
+    }
+    silence_solver_panics();
+    let solver = Solver::new(&ABANDONED);
+    let thread_solver = solver.clone();
+    std::thread::Builder::new()
+        .name(format!("{}{}", SOLVER_THREAD_PREFIX, day))
+        .spawn(move || {
+            f();
+            thread_solver.finish();
+        })
+        .expect("failed to spawn a solver thread");
+    Ok(solver)
+}
+
+/// Installs, once, a panic hook that prints nothing for solver threads and
+/// hands the panics of every other thread to the hook it replaces.
+fn silence_solver_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let thread = std::thread::current();
+            if !thread
+                .name()
+                .is_some_and(|name| name.starts_with(SOLVER_THREAD_PREFIX))
+            {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// The message a panic was raised with, from its `payload`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown cause")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SLOW;
+
+    #[test]
+    fn test_solve_with_timeout() {
+        let day_answers = solve_with_timeout(
+            &crate::y2021::day_07::Day07,
+            String::from("16,1,2,0,4,2,7,1,2,14"),
+            &Part::ALL,
+            DEFAULT_TIMEOUT,
+        )
+        .unwrap();
+        assert_eq!(day_answers.answers[1].answer, "168");
+    }
+
+    #[test]
+    fn test_solve_timed_out() {
+        let day_answers =
+            solve_with_timeout(&SLOW, String::new(), &Part::ALL, Duration::from_millis(10))
+                .unwrap();
+        assert!(day_answers.answers.is_empty());
+        let failures: Vec<String> = day_answers
+            .failures
+            .iter()
+            .map(|failure| failure.to_string())
+            .collect();
+        assert_eq!(
+            failures,
+            [
+                "part 1: gave up after 10.00ms",
+                "part 2: gave up after 10.00ms"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solve_panicked() {
+        let day_answers =
+            solve_with_timeout(&SLOW, String::new(), &[Part::Two], DEFAULT_TIMEOUT).unwrap();
+        assert_eq!(
+            day_answers.failures,
+            [PartFailure {
+                part: Part::Two,
+                error: SolveError::Panicked(String::from("no herd of sea cucumbers")),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_panic_keeps_the_other_answers() {
+        let day_answers = solve_with_timeout(
+            &SLOW,
+            String::new(),
+            &[Part::Two, Part::One],
+            DEFAULT_TIMEOUT,
+        )
+        .unwrap();
+        assert_eq!(day_answers.answers.len(), 1);
+        assert_eq!(day_answers.answers[0].part, Part::One);
+        assert_eq!(day_answers.failures[0].part, Part::Two);
+    }
+
+    #[test]
+    fn test_run_with_timeout() {
+        assert_eq!(run_with_timeout(7, DEFAULT_TIMEOUT, || 42), Ok(42));
+        assert_eq!(
+            run_with_timeout(7, Duration::from_millis(10), || {
+                std::thread::sleep(Duration::from_secs(1))
+            }),
+            Err(SolveError::TimedOut(Duration::from_millis(10)))
+        );
+    }
+
+    #[test]
+    fn test_abandoned_solvers_are_counted_until_they_finish() {
+        static RUNNING: AtomicUsize = AtomicUsize::new(0);
+        let abandoned = Solver::new(&RUNNING);
+        abandoned.abandon();
+        assert_eq!(RUNNING.load(Ordering::SeqCst), 1);
+        abandoned.finish();
+        assert_eq!(RUNNING.load(Ordering::SeqCst), 0);
+
+        let finished = Solver::new(&RUNNING);
+        finished.finish();
+        finished.abandon();
+        assert_eq!(RUNNING.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_solve_bingo_without_winner() {
+        let input = "1,2\n\n 1  2  3  4  5\n 6  7  8  9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25\n";
+        let error = solve_with_timeout(
+            &crate::y2021::day_04::Day04,
+            String::from(input),
+            &[Part::Two],
+            DEFAULT_TIMEOUT,
+        )
+        .unwrap_err();
+        assert!(error.to_string().ends_with(
+            "expected every board to win with the numbers drawn, found \" 1  2  3  4  5\""
+        ));
+    }
+
+    #[test]
+    fn test_solve_parse_error_with_timeout() {
+        let error = solve_with_timeout(
+            &crate::y2021::day_07::Day07,
+            String::from("16,x"),
+            &Part::ALL,
+            DEFAULT_TIMEOUT,
+        )
+        .unwrap_err();
+        assert!(matches!(error, SolveError::Parse(_)));
+    }
+
+    #[test]
+    fn test_solve_selected_parts() {
+        let answers = solve(
+            &crate::y2021::day_07::Day07,
+            "16,1,2,0,4,2,7,1,2,14",
+            &[Part::Two],
+        )
+        .unwrap()
+        .answers;
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].part, Part::Two);
+        assert_eq!(answers[0].answer, "168");
+    }
+}
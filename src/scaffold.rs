@@ -0,0 +1,280 @@
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ScaffoldError {
+    message: String,
+}
+
+impl std::fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+impl ScaffoldError {
+    fn new(message: String) -> Self {
+        Self { message }
+    }
+
+    fn io(path: &Path, err: std::io::Error) -> Self {
+        Self::new(format!("could not write {}: {}", path.display(), err))
+    }
+}
+
+/// Creates `src/yYYYY/day_NN.rs` and empty input and example files under
+/// `root`, and adds the day to the year's module list and solutions. Returns
+/// every file it touched. The year itself must already have a module.
+pub fn create(root: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let year_dir = root.join(format!("src/y{}", year));
+    let module_path = year_dir.join(format!("day_{:02}.rs", day));
+    let year_path = year_dir.join("mod.rs");
+    let input_path = root.join(format!("assets/{}/day_{:02}_input.txt", year, day));
+    let example_path = root.join(format!("assets/{}/examples/day_{:02}.txt", year, day));
+    if !year_path.exists() {
+        return Err(ScaffoldError::new(format!(
+            "there is no module for {} yet, add {} and list it in the registry first",
+            year,
+            year_path.display()
+        )));
+    }
+    if module_path.exists() {
+        return Err(ScaffoldError::new(format!(
+            "{} already exists",
+            module_path.display()
+        )));
+    }
+
+    // Work out every edit before writing anything, so a failure leaves the
+    // tree untouched.
+    let year_module = register_solution(&register_module(&read(&year_path)?, day)?, day)?;
+    write(&module_path, &day_module(day, title))?;
+    write(&year_path, &year_module)?;
+    let mut touched = vec![module_path, year_path];
+    for path in [input_path, example_path] {
+        if !path.exists() {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).map_err(|e| ScaffoldError::io(dir, e))?;
+            }
+            write(&path, "")?;
+            touched.push(path);
+        }
+    }
+    Ok(touched)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    std::fs::read_to_string(path)
+        .map_err(|e| ScaffoldError::new(format!("could not read {}: {}", path.display(), e)))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    std::fs::write(path, contents).map_err(|e| ScaffoldError::io(path, e))
+}
+
+/// The source of a new day module: a [`Solution`] skeleton whose parts answer
+/// nothing yet, and ignored tests for the puzzle's example fixture. The day
+/// reports itself as scaffolded, so the suite stays green until it is solved.
+///
+/// [`Solution`]: advent_of_code_2021::solution::Solution
+pub fn day_module(day: u8, title: &str) -> String {
+    format!(
+        r#"use crate::parse::ParseError;
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+
+const DAY: u8 = {day};
+
+const INFO: PuzzleInfo = PuzzleInfo {{
+    year: super::YEAR,
+    day: DAY,
+    title: {title:?},
+    questions: ["Part one:", "Part two:"],
+}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Vec<String>;
+
+    fn info(&self) -> &'static PuzzleInfo {{
+        &INFO
+    }}
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {{
+        Ok(input.lines().map(String::from).collect())
+    }}
+
+    fn part_one(&self, _lines: &Vec<String>) -> String {{
+        String::new()
+    }}
+
+    fn part_two(&self, _lines: &Vec<String>) -> String {{
+        String::new()
+    }}
+
+    // Remove once both parts are solved, along with the ignore below.
+    fn scaffolded(&self) -> bool {{
+        true
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    crate::examples::example_tests!(Day{day:02}, #[ignore = "day {day} is not solved yet"]);
+}}
+"#
+    )
+}
+
+/// Adds `pub mod day_NN;` to the module list of a year, keeping the days in
+/// order.
+pub fn register_module(year_module: &str, day: u8) -> Result<String, ScaffoldError> {
+    let declaration = format!("pub mod day_{:02};", day);
+    let lines: Vec<&str> = year_module.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return Err(ScaffoldError::new(format!(
+            "day {} is already declared",
+            day
+        )));
+    }
+    let days: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod day_"))
+        .collect();
+    let position = match days.iter().find(|&&i| lines[i] > declaration.as_str()) {
+        Some(&i) => i,
+        None => match days.last() {
+            Some(&i) => i + 1,
+            None => return Err(ScaffoldError::new(String::from("no day modules to add to"))),
+        },
+    };
+    let mut lines: Vec<String> = lines.into_iter().map(String::from).collect();
+    lines.insert(position, declaration);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds `DayNN` to the `SOLUTIONS` of a year, keeping the days in order.
+pub fn register_solution(year_module: &str, day: u8) -> Result<String, ScaffoldError> {
+    let malformed = || ScaffoldError::new(String::from("could not find SOLUTIONS"));
+    let (head, rest) = year_module.split_once("= &[\n").ok_or_else(malformed)?;
+    let (entries, tail) = rest.split_once("];\n").ok_or_else(malformed)?;
+
+    let mut days: Vec<u8> = entries
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("&day_"))
+        .filter_map(|entry| entry.get(..2)?.parse().ok())
+        .collect();
+    if days.contains(&day) {
+        return Err(ScaffoldError::new(format!(
+            "day {} is already registered",
+            day
+        )));
+    }
+    days.push(day);
+    days.sort();
+
+    let entries: String = days
+        .iter()
+        .map(|day| format!("    &day_{:02}::Day{:02},\n", day, day))
+        .collect();
+    Ok(format!("{}= &[\n{}];\n{}", head, entries, tail))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_MODULE: &str = "//! Docs.
+
+use crate::solution::DynSolution;
+
+pub const YEAR: u16 = 2021;
+
+pub mod day_01;
+pub mod day_16;
+
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day_01::Day01,
+    &day_16::Day16,
+];
+";
+
+    #[test]
+    fn test_day_module() {
+        let module = day_module(11, "Dumbo \"Octopus\"");
+        assert!(module.contains("const DAY: u8 = 11;"));
+        assert!(module.contains("pub struct Day11;"));
+        assert!(module.contains(r#""Dumbo \"Octopus\"""#));
+        assert!(module.contains("fn scaffolded(&self) -> bool {\n        true\n    }"));
+        assert!(!module.contains("todo!"));
+        assert!(module.contains(
+            "crate::examples::example_tests!(Day11, #[ignore = \"day 11 is not solved yet\"]);"
+        ));
+    }
+
+    #[test]
+    fn test_register_module() {
+        let updated = register_module(YEAR_MODULE, 11).unwrap();
+        assert!(updated.contains("pub mod day_01;\npub mod day_11;\npub mod day_16;\n"));
+        let updated = register_module(YEAR_MODULE, 20).unwrap();
+        assert!(updated.contains("pub mod day_16;\npub mod day_20;\n\npub static"));
+    }
+
+    #[test]
+    fn test_register_module_twice() {
+        assert!(register_module(YEAR_MODULE, 1).is_err());
+    }
+
+    #[test]
+    fn test_register_solution() {
+        assert_eq!(
+            register_solution(YEAR_MODULE, 11).unwrap(),
+            "//! Docs.
+
+use crate::solution::DynSolution;
+
+pub const YEAR: u16 = 2021;
+
+pub mod day_01;
+pub mod day_16;
+
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day_01::Day01,
+    &day_11::Day11,
+    &day_16::Day16,
+];
+"
+        );
+    }
+
+    #[test]
+    fn test_register_solution_twice() {
+        assert!(register_solution(YEAR_MODULE, 16).is_err());
+    }
+
+    #[test]
+    fn test_register_in_this_crate() {
+        let solutions = advent_of_code_2021::y2021::SOLUTIONS;
+        let day = (1..=25)
+            .find(|&day| solutions.iter().all(|s| s.day() != day))
+            .unwrap();
+        let year_module = include_str!("y2021/mod.rs");
+        let updated = register_solution(&register_module(year_module, day).unwrap(), day).unwrap();
+        assert!(updated.contains(&format!("pub mod day_{:02};\n", day)));
+        assert!(updated.contains(&format!("    &day_{:02}::Day{:02},\n", day, day)));
+    }
+
+    #[test]
+    fn test_create_without_year_module() {
+        let root =
+            std::env::temp_dir().join(format!("advent_of_code_scaffold_{}", std::process::id()));
+        let message = create(&root, 2015, 1, "Not Quite Lisp")
+            .unwrap_err()
+            .to_string();
+        assert!(message.starts_with("there is no module for 2015 yet"));
+        assert!(!root.exists());
+    }
+}
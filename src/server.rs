@@ -0,0 +1,349 @@
+//! A small HTTP server that solves puzzle inputs posted to
+//! `POST /{year}/day/{n}/part/{p}` and answers with JSON. Without the year,
+//! `POST /day/{n}/part/{p}` picks the latest year.
+
+use crate::output::{json_fields, json_string};
+use crate::registry;
+use crate::runner::{self, SolveError};
+use crate::solution::{DynSolution, Part};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::time::Duration;
+
+pub const DEFAULT_PORT: u16 = 2021;
+
+/// Largest request body accepted, well above any real puzzle input.
+pub const MAX_BODY_LEN: usize = 16 * 1024 * 1024;
+
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: String) -> Self {
+        Self { status, body }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, format!("{{\"error\": {}}}", json_string(message)))
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            411 => "Length Required",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+
+    pub fn write_to(&self, w: &mut impl Write) -> std::io::Result<()> {
+        write!(
+            w,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}\n",
+            self.status,
+            self.reason(),
+            self.body.len() + 1,
+            self.body
+        )?;
+        w.flush()
+    }
+}
+
+/// Binds to port `port` on the loopback interface only, so the solvers are
+/// never exposed to the network.
+pub fn bind(port: u16) -> std::io::Result<TcpListener> {
+    TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+}
+
+/// Answers connections on `listener` one at a time, giving each solver
+/// `timeout` to answer. A connection that fails is dropped without stopping
+/// the server.
+pub fn serve(listener: &TcpListener, timeout: Duration) {
+    for stream in listener.incoming().flatten() {
+        let _ = handle_connection(stream, timeout);
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, timeout: Duration) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let response = match read_request(&mut BufReader::new(&stream)) {
+        Ok(request) => handle(&request, timeout),
+        Err(response) => response,
+    };
+    response.write_to(&mut stream)
+}
+
+/// Reads one request, or returns the error response to send instead.
+pub fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let bad_request = |message: &str| Response::error(400, message);
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .map_err(|_| bad_request("could not read the request line"))?;
+    let mut words = line.split_whitespace();
+    let (method, path) = match (words.next(), words.next(), words.next()) {
+        (Some(method), Some(path), Some(version)) if version.starts_with("HTTP/") => {
+            (String::from(method), String::from(path))
+        }
+        _ => return Err(bad_request("malformed request line")),
+    };
+
+    let mut content_length = None;
+    loop {
+        line.clear();
+        reader
+            .read_line(&mut line)
+            .map_err(|_| bad_request("could not read the headers"))?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header
+            .split_once(':')
+            .ok_or_else(|| bad_request("malformed header"))?;
+        if name.eq_ignore_ascii_case("content-length") {
+            let len = value
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| bad_request("invalid Content-Length"))?;
+            content_length = Some(len);
+        }
+    }
+
+    let body = match content_length {
+        Some(len) if len > MAX_BODY_LEN => {
+            return Err(Response::error(413, "the input is too large"));
+        }
+        Some(len) => {
+            let mut body = vec![0; len];
+            reader
+                .read_exact(&mut body)
+                .map_err(|_| bad_request("the body is shorter than its Content-Length"))?;
+            body
+        }
+        None if method == "POST" => {
+            return Err(Response::error(411, "a Content-Length header is required"));
+        }
+        None => Vec::new(),
+    };
+    Ok(Request { method, path, body })
+}
+
+/// Routes a request to the solver it names, which is given `timeout` to parse
+/// the input and answer.
+pub fn handle(request: &Request, timeout: Duration) -> Response {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let (year, day, part) = match segments.as_slice() {
+        ["day", day, "part", part] => (None, *day, *part),
+        [year, "day", day, "part", part] => (Some(*year), *day, *part),
+        _ => return Response::error(404, "no such endpoint, try POST /{year}/day/{n}/part/{p}"),
+    };
+    if request.method != "POST" {
+        return Response::error(405, "only POST is supported");
+    }
+    let year = match year {
+        None => registry::latest_year(),
+        Some(year) => match year.parse() {
+            Ok(year) => year,
+            Err(_) => return Response::error(404, &format!("no such year: {}", year)),
+        },
+    };
+    let solution = match day.parse().ok().and_then(|day| registry::find(year, day)) {
+        Some(solution) => solution,
+        None => {
+            return Response::error(
+                404,
+                &format!("no solution registered for day {} of {}", day, year),
+            )
+        }
+    };
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return Response::error(404, &format!("no part {}, there are 1 and 2", part)),
+    };
+    let input = match std::str::from_utf8(&request.body) {
+        Ok(input) => input,
+        Err(_) => return Response::error(400, "the input is not valid UTF-8"),
+    };
+    answer(solution, part, input, timeout)
+}
+
+/// Solves `part` of `input` on a solver thread. A parse error is the client's
+/// fault, while a solver that panics or runs out of time is the server's.
+fn answer(
+    solution: &'static dyn DynSolution,
+    part: Part,
+    input: &str,
+    timeout: Duration,
+) -> Response {
+    let day_answers =
+        match runner::solve_with_timeout(solution, String::from(input), &[part], timeout) {
+            Ok(day_answers) => day_answers,
+            Err(e) => return solve_error(&e),
+        };
+    if let Some(failure) = day_answers.failures.first() {
+        return solve_error(&failure.error);
+    }
+    Response::json(
+        200,
+        format!(
+            "{{{}, \"parse_duration\": {:.9}}}",
+            json_fields(&day_answers.answers[0]),
+            day_answers.parse_duration.as_secs_f64(),
+        ),
+    )
+}
+
+fn solve_error(e: &SolveError) -> Response {
+    let status = match e {
+        SolveError::Parse(_) => 422,
+        SolveError::Panicked(_) => 500,
+        SolveError::TimedOut(_) | SolveError::Overloaded(_) => 503,
+    };
+    Response::error(status, &e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SLOW;
+    use std::io::Read;
+
+    fn post(path: &str, body: &str) -> Response {
+        handle(
+            &Request {
+                method: String::from("POST"),
+                path: String::from(path),
+                body: body.as_bytes().to_vec(),
+            },
+            runner::DEFAULT_TIMEOUT,
+        )
+    }
+
+    #[test]
+    fn test_read_request() {
+        let raw =
+            "POST /day/7/part/2 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 5\r\n\r\n16,1,2";
+        assert_eq!(
+            read_request(&mut raw.as_bytes()),
+            Ok(Request {
+                method: String::from("POST"),
+                path: String::from("/day/7/part/2"),
+                body: b"16,1,".to_vec(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_read_request_without_length() {
+        let raw = "POST /day/7/part/2 HTTP/1.1\r\n\r\n";
+        assert_eq!(read_request(&mut raw.as_bytes()).unwrap_err().status, 411);
+        let raw = "GET / HTTP/1.1\r\n\r\n";
+        assert!(read_request(&mut raw.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn test_read_malformed_request() {
+        assert_eq!(
+            read_request(&mut "hello\r\n".as_bytes())
+                .unwrap_err()
+                .status,
+            400
+        );
+    }
+
+    #[test]
+    fn test_handle_solves() {
+        let response = post("/day/7/part/2", "16,1,2,0,4,2,7,1,2,14\n");
+        assert_eq!(response.status, 200);
+        assert!(response.body.starts_with(
+            "{\"year\": 2021, \"day\": 7, \"part\": 2, \"title\": \"The Treachery of Whales\""
+        ));
+        assert!(response
+            .body
+            .contains("\"url\": \"https://adventofcode.com/2021/day/7\""));
+        assert!(response.body.contains("\"answer\": \"168\""));
+        assert!(response.body.contains("\"parse_duration\": "));
+    }
+
+    #[test]
+    fn test_handle_with_year() {
+        let response = post("/2021/day/6/part/2", "3,4,3,1,2");
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("\"answer\": \"26984457539\""));
+    }
+
+    #[test]
+    fn test_handle_errors() {
+        assert_eq!(post("/day/25/part/1", "").status, 404);
+        assert_eq!(post("/2015/day/1/part/1", "").status, 404);
+        assert_eq!(post("/day/7/part/3", "").status, 404);
+        assert_eq!(post("/days", "").status, 404);
+        let response = post("/day/7/part/1", "16,x");
+        assert_eq!(response.status, 422);
+        assert!(response
+            .body
+            .starts_with("{\"error\": \"day 7 input, line 1"));
+        let get = handle(
+            &Request {
+                method: String::from("GET"),
+                path: String::from("/day/7/part/1"),
+                body: Vec::new(),
+            },
+            runner::DEFAULT_TIMEOUT,
+        );
+        assert_eq!(get.status, 405);
+    }
+
+    #[test]
+    fn test_answer_timed_out() {
+        let response = answer(&SLOW, Part::One, "", Duration::from_millis(10));
+        assert_eq!(response.status, 503);
+        assert_eq!(response.body, "{\"error\": \"gave up after 10.00ms\"}");
+    }
+
+    #[test]
+    fn test_answer_panicked() {
+        let response = answer(&SLOW, Part::Two, "", runner::DEFAULT_TIMEOUT);
+        assert_eq!(response.status, 500);
+        assert_eq!(
+            response.body,
+            "{\"error\": \"solver panicked: no herd of sea cucumbers\"}"
+        );
+    }
+
+    #[test]
+    fn test_serve_on_localhost() {
+        let listener = bind(0).unwrap();
+        let address = listener.local_addr().unwrap();
+        assert!(address.ip().is_loopback());
+        std::thread::spawn(move || serve(&listener, runner::DEFAULT_TIMEOUT));
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream
+            .write_all(b"POST /day/6/part/1 HTTP/1.1\r\nContent-Length: 9\r\n\r\n3,4,3,1,2")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("\"answer\": \"5934\""));
+    }
+}
@@ -0,0 +1,139 @@
+use crate::inspect::{Command, InspectError};
+use crate::parse::{self, ParseError};
+use crate::puzzle::PuzzleInfo;
+use std::any::Any;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+pub trait Solution {
+    type Input: 'static;
+
+    fn info(&self) -> &'static PuzzleInfo;
+
+    /// Parses the puzzle input. Called through [`DynSolution`], the input has
+    /// been through [`parse::normalize`] first.
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(&self, input: &Self::Input) -> String;
+
+    fn part_two(&self, input: &Self::Input) -> String;
+
+    /// The commands [`Solution::inspect`] understands.
+    fn commands(&self) -> &'static [Command] {
+        &[]
+    }
+
+    /// Runs a REPL command against the parsed input and returns what to print.
+    fn inspect(
+        &self,
+        _input: &Self::Input,
+        _command: &str,
+        _args: &[&str],
+    ) -> Result<String, InspectError> {
+        Err(InspectError::UnknownCommand)
+    }
+
+    /// Whether the day is still the skeleton `new-day` created. The tests that
+    /// cover every registered day skip it until it is solved.
+    fn scaffolded(&self) -> bool {
+        false
+    }
+}
+
+/// Object safe view of a [`Solution`], so days with different input types can
+/// share a registry. The parsed input is passed around as `dyn Any` and
+/// downcast back to `Solution::Input` by the blanket impl below.
+pub trait DynSolution: Sync {
+    fn info(&self) -> &'static PuzzleInfo;
+
+    fn day(&self) -> u8 {
+        self.info().day
+    }
+
+    fn title(&self) -> &'static str {
+        self.info().title
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    fn part_one(&self, input: &dyn Any) -> String;
+
+    fn part_two(&self, input: &dyn Any) -> String;
+
+    fn commands(&self) -> &'static [Command];
+
+    fn inspect(
+        &self,
+        input: &dyn Any,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError>;
+
+    fn scaffolded(&self) -> bool;
+
+    fn solve(&self, part: Part, input: &dyn Any) -> String {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+{
+    fn info(&self) -> &'static PuzzleInfo {
+        Solution::info(self)
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(Solution::parse(self, &parse::normalize(input))?))
+    }
+
+    fn part_one(&self, input: &dyn Any) -> String {
+        Solution::part_one(self, downcast::<S>(input))
+    }
+
+    fn part_two(&self, input: &dyn Any) -> String {
+        Solution::part_two(self, downcast::<S>(input))
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        Solution::commands(self)
+    }
+
+    fn inspect(
+        &self,
+        input: &dyn Any,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        Solution::inspect(self, downcast::<S>(input), command, args)
+    }
+
+    fn scaffolded(&self) -> bool {
+        Solution::scaffolded(self)
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input {
+    input
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by a different solution")
+}
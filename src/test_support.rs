@@ -0,0 +1,78 @@
+//! A fake day for the tests of the code that runs solvers, with parts that
+//! can be made slow or made to panic. The binary includes this file as well,
+//! so it names the library by its crate name.
+
+use advent_of_code_2021::inspect::{Command, InspectError};
+use advent_of_code_2021::parse::ParseError;
+use advent_of_code_2021::puzzle::PuzzleInfo;
+use advent_of_code_2021::solution::{Part, Solution};
+use std::time::Duration;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: 2021,
+    day: 25,
+    title: "Sea Cucumber",
+    questions: ["", ""],
+};
+
+/// Day 25, each part of which sleeps for its `delay_ms` and then panics with
+/// its message in `panics`, if any, or answers with its number. Its only
+/// command, `herd`, always panics.
+pub(crate) struct SeaCucumber {
+    pub delay_ms: [u64; 2],
+    pub panics: [Option<&'static str>; 2],
+}
+
+impl SeaCucumber {
+    fn part(&self, part: Part) -> String {
+        let index = usize::from(part.number() - 1);
+        std::thread::sleep(Duration::from_millis(self.delay_ms[index]));
+        if let Some(message) = self.panics[index] {
+            panic!("{}", message);
+        }
+        part.number().to_string()
+    }
+}
+
+impl Solution for SeaCucumber {
+    type Input = ();
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, _input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part_one(&self, _input: &()) -> String {
+        self.part(Part::One)
+    }
+
+    fn part_two(&self, _input: &()) -> String {
+        self.part(Part::Two)
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[Command {
+            usage: "herd",
+            description: "panics",
+        }]
+    }
+
+    fn inspect(&self, _input: &(), _command: &str, _args: &[&str]) -> Result<String, InspectError> {
+        panic!("no herd to inspect")
+    }
+}
+
+/// Answers both parts at once.
+pub(crate) const QUICK: SeaCucumber = SeaCucumber {
+    delay_ms: [0, 0],
+    panics: [None, None],
+};
+
+/// Takes a second for part one, and panics in part two.
+pub(crate) const SLOW: SeaCucumber = SeaCucumber {
+    delay_ms: [1000, 0],
+    panics: [None, Some("no herd of sea cucumbers")],
+};
@@ -0,0 +1,203 @@
+//! Tracing of intermediate solver state, written to stderr. Solvers call
+//! [`info!`], [`debug!`] or [`trace!`], and nothing is formatted unless that
+//! level is enabled by `--trace` or the `AOC_TRACE` environment variable.
+
+use std::ffi::OsString;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+pub const TRACE_VAR: &str = "AOC_TRACE";
+
+/// How much to trace, each level including the ones before it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// The outcome of each step of a puzzle, like the winning bingo board.
+    Info = 1,
+    /// Every step of a puzzle, like each drawn number.
+    Debug = 2,
+    /// The state of inner loops, like a parser stack after each token.
+    Trace = 3,
+}
+
+impl Level {
+    pub const ALL: [Level; 3] = [Level::Info, Level::Debug, Level::Trace];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct LevelError {
+    value: String,
+}
+
+impl std::fmt::Display for LevelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid trace level: {}, expected info, debug or trace",
+            self.value
+        )
+    }
+}
+
+impl std::error::Error for LevelError {}
+
+impl std::str::FromStr for Level {
+    type Err = LevelError;
+
+    fn from_str(s: &str) -> Result<Self, LevelError> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.name() == s)
+            .ok_or_else(|| LevelError {
+                value: String::from(s),
+            })
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Enables tracing up to `level` if given, and otherwise up to the level in
+/// the `AOC_TRACE` environment variable, if any.
+pub fn init(level: Option<Level>) -> Result<(), LevelError> {
+    set_level(resolve(level, std::env::var_os(TRACE_VAR))?);
+    Ok(())
+}
+
+fn resolve(level: Option<Level>, env_level: Option<OsString>) -> Result<Option<Level>, LevelError> {
+    match (level, env_level) {
+        (Some(level), _) => Ok(Some(level)),
+        (None, Some(env_level)) if !env_level.is_empty() => {
+            env_level.to_string_lossy().parse().map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+pub fn set_level(level: Option<Level>) {
+    LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    #[cfg(test)]
+    if let Some(enabled) = CAPTURE.with(|capture| {
+        capture
+            .borrow()
+            .as_ref()
+            .map(|(max_level, _)| level <= *max_level)
+    }) {
+        return enabled;
+    }
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Writes a message from the module `target`, the path of which is shown
+/// without the crate name.
+pub fn write(level: Level, target: &str, message: std::fmt::Arguments) {
+    let target = target.split_once("::").map_or(target, |(_, path)| path);
+    let line = format!("[{} {}] {}", level.name(), target, message);
+    #[cfg(test)]
+    if CAPTURE.with(|capture| {
+        capture
+            .borrow_mut()
+            .as_mut()
+            .map(|(_, lines)| lines.push(line.clone()))
+            .is_some()
+    }) {
+        return;
+    }
+    eprintln!("{}", line);
+}
+
+macro_rules! event {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::trace::event!($crate::trace::Level::Info, $($arg)+)
+    };
+}
+
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::trace::event!($crate::trace::Level::Debug, $($arg)+)
+    };
+}
+
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::trace::event!($crate::trace::Level::Trace, $($arg)+)
+    };
+}
+
+pub(crate) use {debug, event, info, trace};
+
+#[cfg(test)]
+thread_local! {
+    static CAPTURE: std::cell::RefCell<Option<(Level, Vec<String>)>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Runs `f` with tracing up to `level` on this thread only, and returns the
+/// messages instead of printing them.
+#[cfg(test)]
+pub(crate) fn capture<R>(level: Level, f: impl FnOnce() -> R) -> (R, Vec<String>) {
+    CAPTURE.with(|capture| *capture.borrow_mut() = Some((level, Vec::new())));
+    let result = f();
+    let (_, lines) = CAPTURE.with(|capture| capture.borrow_mut().take()).unwrap();
+    (result, lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!("debug".parse(), Ok(Level::Debug));
+        assert_eq!(
+            "verbose".parse::<Level>().unwrap_err().to_string(),
+            "invalid trace level: verbose, expected info, debug or trace"
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(
+            resolve(Some(Level::Info), Some(OsString::from("trace"))),
+            Ok(Some(Level::Info))
+        );
+        assert_eq!(
+            resolve(None, Some(OsString::from("trace"))),
+            Ok(Some(Level::Trace))
+        );
+        assert_eq!(resolve(None, Some(OsString::new())), Ok(None));
+        assert_eq!(resolve(None, None), Ok(None));
+        assert!(resolve(None, Some(OsString::from("2"))).is_err());
+    }
+
+    #[test]
+    fn test_capture() {
+        let (answer, lines) = capture(Level::Debug, || {
+            info!("start");
+            debug!("step {}", 1);
+            trace!("hidden");
+            42
+        });
+        assert_eq!(answer, 42);
+        assert_eq!(
+            lines,
+            ["[info trace::tests] start", "[debug trace::tests] step 1"]
+        );
+    }
+}
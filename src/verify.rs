@@ -0,0 +1,193 @@
+use crate::runner::Answer;
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct AnswersError {
+    line: usize,
+    message: String,
+}
+
+impl std::fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "answers file, line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// Known-correct answers, read from a file with one `<day> <part> <answer>`
+/// entry per line. Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl ExpectedAnswers {
+    pub fn parse(input: &str) -> Result<Self, AnswersError> {
+        let mut answers = BTreeMap::new();
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| AnswersError {
+                line: i + 1,
+                message: format!("{}: {}", message, line),
+            };
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() != 3 {
+                return Err(error("expected <day> <part> <answer>"));
+            }
+            let day = parts[0].parse::<u8>().map_err(|_| error("invalid day"))?;
+            let part = parts[1]
+                .parse::<u8>()
+                .ok()
+                .filter(|part| [1, 2].contains(part))
+                .ok_or_else(|| error("invalid part"))?;
+            if answers
+                .insert((day, part), String::from(parts[2]))
+                .is_some()
+            {
+                return Err(error("duplicate answer"));
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    /// Whether there is an expected answer for either part of `day`.
+    pub fn has_day(&self, day: u8) -> bool {
+        self.answers
+            .range((day, 0)..=(day, u8::MAX))
+            .next()
+            .is_some()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Mismatch { expected: String },
+    Missing,
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub answer: Answer,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Correct
+    }
+}
+
+pub fn check(expected: &ExpectedAnswers, answers: Vec<Answer>) -> Vec<Check> {
+    answers
+        .into_iter()
+        .map(|answer| {
+            let outcome = match expected.get(answer.puzzle.day, answer.part.number()) {
+                Some(expected) if expected == answer.answer => Outcome::Correct,
+                Some(expected) => Outcome::Mismatch {
+                    expected: String::from(expected),
+                },
+                None => Outcome::Missing,
+            };
+            Check { answer, outcome }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+    use std::time::Duration;
+
+    const INPUT: &str = "# day part answer
+1 1 7
+1 2 5
+
+6 1 5934
+";
+
+    fn answer(day: u8, part: Part, answer: &str) -> Answer {
+        Answer {
+            puzzle: crate::registry::find(2021, day).unwrap().info(),
+            part,
+            answer: String::from(answer),
+            duration: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = ExpectedAnswers::parse(INPUT).unwrap();
+        assert_eq!(expected.get(1, 2), Some("5"));
+        assert_eq!(expected.get(6, 1), Some("5934"));
+        assert_eq!(expected.get(6, 2), None);
+    }
+
+    #[test]
+    fn test_has_day() {
+        let expected = ExpectedAnswers::parse(INPUT).unwrap();
+        assert!(expected.has_day(1));
+        assert!(expected.has_day(6));
+        assert!(!expected.has_day(2));
+    }
+
+    #[test]
+    fn test_parse_invalid_line() {
+        let error = ExpectedAnswers::parse("1 1 7\n1 3 5").unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn test_parse_duplicate() {
+        assert!(ExpectedAnswers::parse("1 1 7\n1 1 8").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let expected = ExpectedAnswers::parse(INPUT).unwrap();
+        let checks = check(
+            &expected,
+            vec![
+                answer(1, Part::One, "7"),
+                answer(1, Part::Two, "6"),
+                answer(6, Part::Two, "26984457539"),
+            ],
+        );
+        assert_eq!(checks[0].outcome, Outcome::Correct);
+        assert_eq!(
+            checks[1].outcome,
+            Outcome::Mismatch {
+                expected: String::from("5")
+            }
+        );
+        assert_eq!(checks[2].outcome, Outcome::Missing);
+    }
+
+    #[test]
+    fn test_answers_file_covers_every_day() {
+        let input = std::fs::read_to_string(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/2021/answers.txt"),
+        )
+        .unwrap();
+        let expected = ExpectedAnswers::parse(&input).unwrap();
+        for solution in crate::y2021::SOLUTIONS.iter().filter(|s| !s.scaffolded()) {
+            for part in Part::ALL {
+                assert!(
+                    expected.get(solution.day(), part.number()).is_some(),
+                    "no answer for day {} part {}",
+                    solution.day(),
+                    part.number()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// What a file looked like the last time it was polled. A missing file is a
+/// state of its own, so deleting and recreating a file counts as a change.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Stamp {
+    Missing,
+    Present {
+        modified: Option<SystemTime>,
+        len: u64,
+    },
+}
+
+/// Watches a file for changes by polling its modification time and length.
+pub struct Watcher {
+    path: PathBuf,
+    stamp: Stamp,
+}
+
+impl Watcher {
+    pub fn new(path: PathBuf) -> Self {
+        let stamp = Self::stamp(&path);
+        Self { path, stamp }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether the file changed since the watcher was created or
+    /// last polled.
+    pub fn poll(&mut self) -> bool {
+        let stamp = Self::stamp(&self.path);
+        let changed = stamp != self.stamp;
+        self.stamp = stamp;
+        changed
+    }
+
+    /// Blocks until the file changes, checking every `interval`.
+    pub fn wait(&mut self, interval: Duration) {
+        while !self.poll() {
+            std::thread::sleep(interval);
+        }
+    }
+
+    fn stamp(path: &Path) -> Stamp {
+        match std::fs::metadata(path) {
+            Ok(metadata) => Stamp::Present {
+                modified: metadata.modified().ok(),
+                len: metadata.len(),
+            },
+            Err(_) => Stamp::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "advent_of_code_2021_{}_{}",
+            std::process::id(),
+            name
+        ))
+    }
+
+    #[test]
+    fn test_poll_unchanged_file() {
+        let path = temp_path("unchanged");
+        std::fs::write(&path, "3,4,3,1,2").unwrap();
+        let mut watcher = Watcher::new(path.clone());
+        assert!(!watcher.poll());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_poll_changed_file() {
+        let path = temp_path("changed");
+        std::fs::write(&path, "3,4,3,1,2").unwrap();
+        let mut watcher = Watcher::new(path.clone());
+        std::fs::write(&path, "3,4,3,1,2,5").unwrap();
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_poll_created_and_deleted_file() {
+        let path = temp_path("created");
+        let mut watcher = Watcher::new(path.clone());
+        assert!(!watcher.poll());
+        std::fs::write(&path, "16,1,2").unwrap();
+        assert!(watcher.poll());
+        std::fs::remove_file(&path).unwrap();
+        assert!(watcher.poll());
+    }
+}
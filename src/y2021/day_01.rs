@@ -0,0 +1,181 @@
+use crate::inspect::{self, Command, InspectError};
+use crate::parse::{self, ParseError};
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+
+const DAY: u8 = 1;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "Sonar Sweep",
+    questions: [
+        "How many measurements are larger than the previous measurement?",
+        "Consider sums of a three-measurement sliding window. How many sums are larger than the previous sum?",
+    ],
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Sonar;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<Sonar, ParseError> {
+        Sonar::new(input)
+    }
+
+    fn part_one(&self, sonar: &Sonar) -> String {
+        sonar.count_depth_measurement_increases().to_string()
+    }
+
+    fn part_two(&self, sonar: &Sonar) -> String {
+        sonar.count_depth_sliding_sum_increases(3).to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "depth INDEX",
+                description: "the depth measurement at INDEX",
+            },
+            Command {
+                usage: "increases",
+                description: "how many measurements are deeper than the one before",
+            },
+            Command {
+                usage: "windows SIZE",
+                description: "how many sliding sums of SIZE measurements increase",
+            },
+        ]
+    }
+
+    fn inspect(&self, sonar: &Sonar, command: &str, args: &[&str]) -> Result<String, InspectError> {
+        match command {
+            "depth" => {
+                let index = inspect::argument(args, "depth INDEX")?;
+                Ok(inspect::item(&sonar.depth_measurements, index, "measurement")?.to_string())
+            }
+            "increases" => {
+                inspect::no_arguments(args, "increases")?;
+                Ok(sonar.count_depth_measurement_increases().to_string())
+            }
+            "windows" => {
+                let size: usize = inspect::argument(args, "windows SIZE")?;
+                if size == 0 || size > sonar.depth_measurements.len() {
+                    return Err(InspectError::Invalid(format!(
+                        "the window size must be from 1 to {}",
+                        sonar.depth_measurements.len()
+                    )));
+                }
+                Ok(sonar.count_depth_sliding_sum_increases(size).to_string())
+            }
+            _ => Err(InspectError::UnknownCommand),
+        }
+    }
+}
+
+pub struct Sonar {
+    depth_measurements: Vec<u16>,
+}
+
+impl Sonar {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let depth_measurements = input
+            .lines()
+            .map(|line| parse::number(DAY, input, line, "a depth measurement"))
+            .collect::<Result<Vec<_>, _>>()?;
+        if depth_measurements.is_empty() {
+            return Err(ParseError::new(
+                DAY,
+                input,
+                input,
+                "expected a depth measurement",
+            ));
+        }
+        Ok(Self { depth_measurements })
+    }
+
+    pub fn count_depth_measurement_increases(&self) -> usize {
+        let mut results = 0;
+        for i in 1..self.depth_measurements.len() {
+            if self.depth_measurements[i - 1] < self.depth_measurements[i] {
+                results += 1;
+            }
+        }
+        results
+    }
+
+    pub fn count_depth_sliding_sum_increases(&self, sliding_window_size: usize) -> usize {
+        let mut results = 0;
+        let windows = self
+            .depth_measurements
+            .len()
+            .saturating_sub(sliding_window_size - 1);
+        for i in 1..windows {
+            let previous_sum = self.depth_measurements[i - 1..i + (sliding_window_size - 1)]
+                .iter()
+                .sum::<u16>();
+            let current_sum = self.depth_measurements[i..i + sliding_window_size]
+                .iter()
+                .sum::<u16>();
+            if previous_sum < current_sum {
+                results += 1
+            }
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples::example_tests!(Day01);
+
+    const INPUT: &str = include_str!("../../assets/2021/examples/day_01.txt");
+
+    #[test]
+    fn test_count_depth_measurement_increases() {
+        let sonar = Sonar::new(INPUT).unwrap();
+        assert_eq!(sonar.count_depth_measurement_increases(), 7);
+    }
+
+    #[test]
+    fn test_count_depth_sliding_sum_increases() {
+        let sonar = Sonar::new(INPUT).unwrap();
+        assert_eq!(sonar.count_depth_sliding_sum_increases(3), 5);
+    }
+
+    #[test]
+    fn test_invalid_depth_measurement() {
+        let error = Sonar::new("199\n2x0\n208").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "2x0");
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let error = Sonar::new("").err().unwrap();
+        assert_eq!(error.message, "expected a depth measurement");
+    }
+
+    #[test]
+    fn test_fewer_measurements_than_window() {
+        let sonar = Sonar::new("199\n200\n").unwrap();
+        assert_eq!(sonar.count_depth_sliding_sum_increases(3), 0);
+    }
+
+    #[test]
+    fn test_inspect_windows() {
+        let sonar = Sonar::new(INPUT).unwrap();
+        assert_eq!(
+            Day01.inspect(&sonar, "windows", &["3"]),
+            Ok(String::from("5"))
+        );
+        assert!(Day01.inspect(&sonar, "windows", &["11"]).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use crate::inspect::{self, Command, InspectError};
+use crate::parse::{self, ParseError};
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+
+const DAY: u8 = 2;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "Dive!",
+    questions: [
+        "What do you get if you multiply your final horizontal position by your final depth?",
+        "Using the new interpretation of the commands, what do you get if you multiply your final horizontal position by your final depth?",
+    ],
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<CourseStep>;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<CourseStep>, ParseError> {
+        get_course(input)
+    }
+
+    fn part_one(&self, course: &Vec<CourseStep>) -> String {
+        let (position, depth) = plot_course_incorrect(course);
+        (position * depth).to_string()
+    }
+
+    fn part_two(&self, course: &Vec<CourseStep>) -> String {
+        let (position, depth) = plot_course(course);
+        (position * depth).to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "position",
+                description: "position and depth when steering without aim",
+            },
+            Command {
+                usage: "aimed-position",
+                description: "position and depth when steering with aim",
+            },
+        ]
+    }
+
+    fn inspect(
+        &self,
+        course: &Vec<CourseStep>,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        let (position, depth) = match command {
+            "position" => {
+                inspect::no_arguments(args, "position")?;
+                plot_course_incorrect(course)
+            }
+            "aimed-position" => {
+                inspect::no_arguments(args, "aimed-position")?;
+                plot_course(course)
+            }
+            _ => return Err(InspectError::UnknownCommand),
+        };
+        Ok(format!("position {}, depth {}", position, depth))
+    }
+}
+
+pub enum CourseStep {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+pub fn get_course(input: &str) -> Result<Vec<CourseStep>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() != 2 {
+                return Err(ParseError::new(
+                    DAY,
+                    input,
+                    line,
+                    "expected a command followed by a distance",
+                ));
+            }
+            let value = parse::number(DAY, input, parts[1], "a distance")?;
+            match parts[0] {
+                "forward" => Ok(CourseStep::Forward(value)),
+                "down" => Ok(CourseStep::Down(value)),
+                "up" => Ok(CourseStep::Up(value)),
+                _ => Err(ParseError::new(
+                    DAY,
+                    input,
+                    parts[0],
+                    "expected forward, down or up",
+                )),
+            }
+        })
+        .collect()
+}
+
+pub fn plot_course_incorrect(course: &[CourseStep]) -> (i64, i64) {
+    course
+        .iter()
+        .fold((0, 0), |(position, depth), course_step| match course_step {
+            CourseStep::Forward(value) => (position + value, depth),
+            CourseStep::Down(value) => (position, depth + value),
+            CourseStep::Up(value) => (position, depth - value),
+        })
+}
+
+pub fn plot_course(course: &[CourseStep]) -> (i64, i64) {
+    let (position, depth, _) = course.iter().fold(
+        (0, 0, 0),
+        |(position, depth, aim), course_step| match course_step {
+            CourseStep::Forward(value) => (position + value, depth + aim * value, aim),
+            CourseStep::Down(value) => (position, depth, aim + value),
+            CourseStep::Up(value) => (position, depth, aim - value),
+        },
+    );
+    (position, depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples::example_tests!(Day02);
+}
@@ -0,0 +1,264 @@
+use crate::inspect::{self, Command, InspectError};
+use crate::parse::ParseError;
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+use crate::trace;
+
+const DAY: u8 = 3;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "Binary Diagnostic",
+    questions: [
+        "What is the power consumption of the submarine?",
+        "What is the life support rating of the submarine?",
+    ],
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = DiagnosticReport;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<DiagnosticReport, ParseError> {
+        get_diagnostic_report(input)
+    }
+
+    fn part_one(&self, report: &DiagnosticReport) -> String {
+        calculate_power_consumption(report).to_string()
+    }
+
+    fn part_two(&self, report: &DiagnosticReport) -> String {
+        calculate_life_support_rating(report).to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "entry INDEX",
+                description: "the diagnostic report entry at INDEX",
+            },
+            Command {
+                usage: "power",
+                description: "the power consumption",
+            },
+            Command {
+                usage: "life-support",
+                description: "the life support rating",
+            },
+        ]
+    }
+
+    fn inspect(
+        &self,
+        report: &DiagnosticReport,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        match command {
+            "entry" => {
+                let index = inspect::argument(args, "entry INDEX")?;
+                Ok(format!(
+                    "{:0width$b}",
+                    inspect::item(&report.entries, index, "entry")?,
+                    width = report.width
+                ))
+            }
+            "power" => {
+                inspect::no_arguments(args, "power")?;
+                Ok(calculate_power_consumption(report).to_string())
+            }
+            "life-support" => {
+                inspect::no_arguments(args, "life-support")?;
+                Ok(calculate_life_support_rating(report).to_string())
+            }
+            _ => Err(InspectError::UnknownCommand),
+        }
+    }
+}
+
+/// The report entries, all `width` bits wide.
+pub struct DiagnosticReport {
+    pub width: usize,
+    pub entries: Vec<u16>,
+}
+
+pub fn get_diagnostic_report(input: &str) -> Result<DiagnosticReport, ParseError> {
+    let first_line = input.lines().next().unwrap_or(input);
+    let width = first_line.len();
+    if width == 0 {
+        return Err(ParseError::new(
+            DAY,
+            input,
+            first_line,
+            "expected a binary number",
+        ));
+    }
+    let entries = input
+        .lines()
+        .map(|line| {
+            if line.len() != width || width > 16 {
+                return Err(ParseError::new(
+                    DAY,
+                    input,
+                    line,
+                    format!("expected a binary number of {} digits", width.min(16)),
+                ));
+            }
+            u16::from_str_radix(line, 2)
+                .map_err(|_| ParseError::new(DAY, input, line, "expected a binary number"))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(DiagnosticReport { width, entries })
+}
+
+pub fn calculate_power_consumption(report: &DiagnosticReport) -> u64 {
+    let bit_count = report
+        .entries
+        .iter()
+        .fold(vec![0; report.width], |mut acc, entry| {
+            for (i, count) in acc.iter_mut().enumerate() {
+                *count += usize::from((entry >> i) & 1);
+            }
+            acc
+        });
+    let gamma_rate = u64::from_str_radix(
+        &bit_count
+            .iter()
+            .rev()
+            .map(|count| {
+                if *count > report.entries.len() / 2 {
+                    "1"
+                } else {
+                    "0"
+                }
+            })
+            .collect::<String>(),
+        2,
+    )
+    .unwrap();
+    let epsilon_rate = !gamma_rate & ((1 << report.width) - 1);
+    gamma_rate * epsilon_rate
+}
+
+pub fn calculate_life_support_rating(report: &DiagnosticReport) -> u64 {
+    let oxygen_generator_rating: u64 = filter(report, |count, length| {
+        if count as f32 >= length as f32 / 2.0 {
+            1
+        } else {
+            0
+        }
+    })
+    .into();
+    let co2_scrubber_rating: u64 = filter(report, |count, length| {
+        if (count as f32) < length as f32 / 2.0 {
+            1
+        } else {
+            0
+        }
+    })
+    .into();
+    trace::info!(
+        "oxygen generator rating {}, CO2 scrubber rating {}",
+        oxygen_generator_rating,
+        co2_scrubber_rating
+    );
+    oxygen_generator_rating * co2_scrubber_rating
+}
+
+fn filter<F>(report: &DiagnosticReport, bit_criteria: F) -> u16
+where
+    F: Fn(usize, usize) -> u16,
+{
+    let mut entries = report.entries.clone();
+    let mut bit_index = report.width;
+    while entries.len() > 1 && bit_index > 0 {
+        bit_index -= 1;
+        let bit_count = entries
+            .iter()
+            .fold(0, |acc, &entry| acc + usize::from((entry >> bit_index) & 1));
+        let before = entries.len();
+        let mask = bit_criteria(bit_count, before);
+        // When every entry has the same bit, the criteria may match none of
+        // them, so the bit is skipped rather than discarding every entry.
+        if entries
+            .iter()
+            .any(|&entry| (entry >> bit_index) & 1 == mask)
+        {
+            entries.retain(|&entry| (entry >> bit_index) & 1 == mask);
+        }
+        trace::debug!(
+            "bit {}: {} of {} set, keeping {}, {} left",
+            bit_index,
+            bit_count,
+            before,
+            mask,
+            entries.len()
+        );
+    }
+    entries[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples::example_tests!(Day03);
+
+    #[test]
+    fn test_entry_keeps_the_report_width() {
+        let report = get_diagnostic_report("00100\n11110\n").unwrap();
+        assert_eq!(report.width, 5);
+        assert_eq!(Day03.inspect(&report, "entry", &["0"]).unwrap(), "00100");
+    }
+
+    #[test]
+    fn test_empty_input() {
+        for input in ["", "\n0101\n"] {
+            let error = get_diagnostic_report(input).err().unwrap();
+            assert_eq!(error.message, "expected a binary number");
+        }
+    }
+
+    #[test]
+    fn test_entries_of_different_widths() {
+        let error = get_diagnostic_report("00100\n1111\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_life_support_rating_with_a_shared_bit() {
+        let report = get_diagnostic_report("101\n110\n111\n").unwrap();
+        assert_eq!(calculate_life_support_rating(&report), 0b111 * 0b101);
+    }
+
+    #[test]
+    fn test_more_entries_than_a_u16_counts() {
+        let input = "10\n".repeat(40_000) + &"01\n".repeat(30_000);
+        let report = get_diagnostic_report(&input).unwrap();
+        assert_eq!(calculate_power_consumption(&report), 2);
+        assert_eq!(calculate_life_support_rating(&report), 2);
+    }
+
+    #[test]
+    fn test_trace_filter_steps() {
+        let report =
+            get_diagnostic_report(include_str!("../../assets/2021/examples/day_03.txt")).unwrap();
+        let (_, lines) = trace::capture(trace::Level::Debug, || {
+            calculate_life_support_rating(&report)
+        });
+        assert_eq!(
+            lines[0],
+            "[debug y2021::day_03] bit 4: 7 of 12 set, keeping 1, 7 left"
+        );
+        assert_eq!(
+            lines.last().unwrap(),
+            "[info y2021::day_03] oxygen generator rating 23, CO2 scrubber rating 10"
+        );
+    }
+}
@@ -0,0 +1,416 @@
+use crate::grid::Grid;
+use crate::inspect::{self, Command, InspectError};
+use crate::parse::{self, ParseError};
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+use crate::trace;
+
+const DAY: u8 = 4;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "Giant Squid",
+    questions: [
+        "What will your final score be if you choose the board which will win first?",
+        "Once the last board wins, what would its final score be?",
+    ],
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = BingoSubsystem;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<BingoSubsystem, ParseError> {
+        BingoSubsystem::new(input)
+    }
+
+    fn part_one(&self, bingo: &BingoSubsystem) -> String {
+        bingo.clone().get_winning_score().to_string()
+    }
+
+    fn part_two(&self, bingo: &BingoSubsystem) -> String {
+        bingo.clone().get_last_score().to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "numbers",
+                description: "the numbers in the order they are drawn",
+            },
+            Command {
+                usage: "board INDEX [DRAWS]",
+                description: "board INDEX, marked after the first DRAWS numbers",
+            },
+            Command {
+                usage: "winner",
+                description: "the score of the first board to win",
+            },
+            Command {
+                usage: "last",
+                description: "the score of the last board to win",
+            },
+        ]
+    }
+
+    fn inspect(
+        &self,
+        bingo: &BingoSubsystem,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        match command {
+            "numbers" => {
+                inspect::no_arguments(args, "numbers")?;
+                let numbers: Vec<String> = bingo.numbers.iter().map(|n| n.to_string()).collect();
+                Ok(numbers.join(","))
+            }
+            "board" => {
+                let usage = || InspectError::Usage("board INDEX [DRAWS]");
+                let (index, draws) = match args {
+                    [index] => (index.parse().map_err(|_| usage())?, 0),
+                    [index, draws] => (
+                        index.parse().map_err(|_| usage())?,
+                        draws.parse().map_err(|_| usage())?,
+                    ),
+                    _ => return Err(usage()),
+                };
+                let mut board = inspect::item(&bingo.boards, index, "board")?.clone();
+                for &n in bingo.numbers.iter().take(draws) {
+                    board.mark_number(n);
+                }
+                Ok(board.to_string())
+            }
+            "winner" => {
+                inspect::no_arguments(args, "winner")?;
+                Ok(bingo.clone().get_winning_score().to_string())
+            }
+            "last" => {
+                inspect::no_arguments(args, "last")?;
+                Ok(bingo.clone().get_last_score().to_string())
+            }
+            _ => Err(InspectError::UnknownCommand),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BingoSubsystem {
+    numbers: Vec<u16>,
+    boards: Vec<BingoBoard>,
+}
+
+impl BingoSubsystem {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let blocks = parse::blocks(input);
+        let first_block = blocks.first().copied().unwrap_or(input);
+        let numbers = parse::number_list(DAY, input, first_block, ',', "a bingo number")?;
+        if blocks.len() < 2 {
+            return Err(ParseError::new(
+                DAY,
+                input,
+                &input[input.len()..],
+                "expected a board after the numbers",
+            ));
+        }
+        let mut boards = Vec::with_capacity(blocks.len() - 1);
+        for block in blocks.iter().skip(1) {
+            let board = BingoBoard::new(input, &block.lines().collect::<Vec<&str>>())?;
+            let mut marked = board.clone();
+            for &n in numbers.iter() {
+                marked.mark_number(n);
+            }
+            if !marked.has_won() {
+                return Err(ParseError::new(
+                    DAY,
+                    input,
+                    block.lines().next().unwrap_or(block),
+                    "expected every board to win with the numbers drawn",
+                ));
+            }
+            boards.push(board);
+        }
+        Ok(Self { numbers, boards })
+    }
+
+    pub fn get_winning_score(&mut self) -> u64 {
+        for n in self.numbers.iter() {
+            trace::debug!("drew {}", n);
+            for (i, board) in self.boards.iter_mut().enumerate() {
+                board.mark_number(*n);
+                if board.has_won() {
+                    let score = board.get_score(*n);
+                    trace::info!("board {} wins first on {} with score {}", i, n, score);
+                    return score;
+                }
+            }
+        }
+
+        panic!("no winning board, though parsing checks that every board wins")
+    }
+
+    /// The score of the board that wins last. When the last boards win on the
+    /// same number, the first of them counts.
+    pub fn get_last_score(&mut self) -> u64 {
+        let mut remaining_boards: Vec<(usize, BingoBoard)> =
+            self.boards.iter().cloned().enumerate().collect();
+        for &n in self.numbers.iter() {
+            trace::debug!("drew {}", n);
+            for (_, board) in remaining_boards.iter_mut() {
+                board.mark_number(n);
+            }
+            if remaining_boards.iter().all(|(_, board)| board.has_won()) {
+                let (j, last_board) = &remaining_boards[0];
+                let score = last_board.get_score(n);
+                trace::info!("board {} wins last on {} with score {}", j, n, score);
+                return score;
+            }
+            remaining_boards.retain(|(j, board)| {
+                if board.has_won() {
+                    trace::debug!("board {} wins on {}", j, n);
+                }
+                !board.has_won()
+            });
+        }
+
+        panic!("no last winning board, though parsing checks that every board wins")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BingoBoard {
+    grid: Grid<(u16, bool)>,
+}
+
+impl BingoBoard {
+    /// Parses the rows of a board. Every row must be a slice of `input`, so
+    /// that errors can point at their position in the whole input.
+    pub(crate) fn new(input: &str, lines: &[&str]) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(25);
+        for &line in lines.iter().filter(|line| !line.trim().is_empty()) {
+            let row: Vec<&str> = line.split_whitespace().collect();
+            if row.len() != 5 {
+                return Err(ParseError::new(
+                    DAY,
+                    input,
+                    line,
+                    format!("expected 5 numbers in a board row, found {}", row.len()),
+                ));
+            }
+            for n in row {
+                let n = parse::number(DAY, input, n, "a board number")?;
+                cells.push((n, false));
+            }
+        }
+        if cells.len() != 25 {
+            let text = lines.first().copied().unwrap_or(&input[input.len()..]);
+            return Err(ParseError::new(
+                DAY,
+                input,
+                text,
+                "expected a board of 5 rows",
+            ));
+        }
+        Ok(Self {
+            grid: Grid::new(5, 5, cells),
+        })
+    }
+
+    pub fn mark_number(&mut self, n: u16) {
+        if let Some(tile) = self.grid.iter_mut().find(|tile| tile.0 == n) {
+            tile.1 = true
+        }
+    }
+
+    pub fn is_tile_marked(&self, column: usize, row: usize) -> bool {
+        self.grid[(row, column)].1
+    }
+
+    fn check_column(&self, column: usize) -> bool {
+        self.grid.column(column).all(|tile| tile.1)
+    }
+
+    fn check_columns(&self) -> bool {
+        (0..self.grid.columns()).any(|column| self.check_column(column))
+    }
+
+    fn check_row(&self, row: usize) -> bool {
+        self.grid.row(row).all(|tile| tile.1)
+    }
+
+    fn check_rows(&self) -> bool {
+        (0..self.grid.rows()).any(|row| self.check_row(row))
+    }
+
+    pub fn has_won(&self) -> bool {
+        self.check_columns() || self.check_rows()
+    }
+
+    pub fn get_score(&self, last_called: u16) -> u64 {
+        self.grid
+            .iter()
+            .filter(|&&tile| !tile.1)
+            .map(|&tile| tile.0 as u64)
+            .sum::<u64>()
+            * last_called as u64
+    }
+}
+
+/// Shows the board row by row, with a `*` after every marked number.
+impl std::fmt::Display for BingoBoard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for row in 0..self.grid.rows() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            let tiles: Vec<String> = self
+                .grid
+                .row(row)
+                .map(|&(n, marked)| format!("{:>2}{}", n, if marked { "*" } else { " " }))
+                .collect();
+            write!(f, "{}", tiles.join(" ").trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    crate::examples::example_tests!(Day04);
+
+    const INPUT: &str = include_str!("../../assets/2021/examples/day_04.txt");
+
+    #[test]
+    fn test_board_mark_number() {
+        let mut board =
+            BingoBoard::new(INPUT, &INPUT.lines().skip(2).take(6).collect::<Vec<&str>>()).unwrap();
+        board.mark_number(7);
+        assert!(board.is_tile_marked(4, 2))
+    }
+
+    #[test]
+    fn test_board_has_won() {
+        let mut board = BingoBoard::new(
+            INPUT,
+            &INPUT.lines().skip(14).take(6).collect::<Vec<&str>>(),
+        )
+        .unwrap();
+        for n in [7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24] {
+            board.mark_number(n);
+        }
+        assert!(board.has_won())
+    }
+
+    #[test]
+    fn test_board_score() {
+        let mut bingo = BingoSubsystem::new(INPUT).unwrap();
+        assert_eq!(bingo.get_winning_score(), 4512)
+    }
+
+    #[test]
+    fn test_last_board_score() {
+        let mut bingo = BingoSubsystem::new(INPUT).unwrap();
+        assert_eq!(bingo.get_last_score(), 1924)
+    }
+
+    #[test]
+    fn test_last_boards_winning_together() {
+        let row = |first: u16| -> String {
+            (first..first + 5)
+                .map(|n| format!("{:>2} ", n))
+                .collect::<String>()
+                + "\n"
+        };
+        let board: String = [1, 6, 11, 16, 21].iter().map(|&first| row(first)).collect();
+        let input = format!("1,2,3,4,5\n\n{}\n{}", board, board);
+        let mut bingo = BingoSubsystem::new(&input).unwrap();
+        assert_eq!(bingo.get_last_score(), (6..=25).sum::<u64>() * 5);
+    }
+
+    #[test]
+    fn test_board_that_never_wins() {
+        let board = INPUT
+            .lines()
+            .skip(2)
+            .take(5)
+            .collect::<Vec<&str>>()
+            .join("\n");
+        let error = BingoSubsystem::new(&format!("22,13\n\n{}\n", board))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(
+            error.message,
+            "expected every board to win with the numbers drawn"
+        );
+    }
+
+    #[test]
+    fn test_numbers_without_boards() {
+        let error = BingoSubsystem::new("7,4,9\n").err().unwrap();
+        assert_eq!(error.message, "expected a board after the numbers");
+    }
+
+    #[test]
+    fn test_invalid_board_row() {
+        let input = INPUT.replacen("21  9 14 16  7", "21  9 14 16", 1);
+        let error = BingoSubsystem::new(&input).err().unwrap();
+        assert_eq!((error.line, error.column), (5, 1));
+    }
+
+    #[test]
+    fn test_invalid_bingo_number() {
+        let error = BingoSubsystem::new("7,4,x9").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert_eq!(error.text, "x9");
+    }
+
+    #[test]
+    fn test_inspect_board() {
+        let bingo = BingoSubsystem::new(INPUT).unwrap();
+        assert_eq!(
+            Day04.inspect(&bingo, "board", &["2", "12"]),
+            Ok(String::from(
+                "14* 21* 17* 24*  4*
+10  16  15   9* 19
+18   8  23* 26  20
+22  11* 13   6   5*
+ 2*  0* 12   3   7*"
+            ))
+        );
+        assert!(Day04.inspect(&bingo, "board", &["3"]).is_err());
+    }
+
+    #[test]
+    fn test_crlf_input_with_bom() {
+        let input = format!("\u{feff}{}\r\n\r\n", INPUT.replace('\n', "\r\n"));
+        let answers = crate::runner::solve(&Day04, &input, &Part::ALL).unwrap();
+        assert_eq!(answers.answers[0].answer, "4512");
+        assert_eq!(answers.answers[1].answer, "1924");
+    }
+
+    #[test]
+    fn test_trace_winning_boards() {
+        let mut bingo = BingoSubsystem::new(INPUT).unwrap();
+        let (_, lines) = trace::capture(trace::Level::Info, || {
+            bingo.get_winning_score();
+            bingo.get_last_score();
+        });
+        assert_eq!(
+            lines,
+            [
+                "[info y2021::day_04] board 2 wins first on 24 with score 4512",
+                "[info y2021::day_04] board 1 wins last on 13 with score 1924",
+            ]
+        );
+    }
+}
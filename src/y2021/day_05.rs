@@ -0,0 +1,339 @@
+use crate::inspect::{self, Command, InspectError};
+use crate::parse::{self, ParseError};
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+
+const DAY: u8 = 5;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "Hydrothermal Venture",
+    questions: [
+        "Considering only horizontal and vertical vent lines, how many points do at least two vent lines overlap?",
+        "Considering all vent lines, how many points do at least two vent lines overlap?",
+    ],
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<VentLine>;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<VentLine>, ParseError> {
+        parse_vent_lines(input)
+    }
+
+    fn part_one(&self, lines: &Vec<VentLine>) -> String {
+        let map = VentMap::new(lines, false);
+        map.get_multiple_vent_point_count().to_string()
+    }
+
+    fn part_two(&self, lines: &Vec<VentLine>) -> String {
+        let map = VentMap::new(lines, true);
+        map.get_multiple_vent_point_count().to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "line INDEX",
+                description: "the vent line at INDEX",
+            },
+            Command {
+                usage: "vents X Y",
+                description: "how many vent lines, diagonals included, cross X,Y",
+            },
+            Command {
+                usage: "overlaps",
+                description: "points where lines overlap, without and with diagonals",
+            },
+        ]
+    }
+
+    fn inspect(
+        &self,
+        lines: &Vec<VentLine>,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        match command {
+            "line" => {
+                let index = inspect::argument(args, "line INDEX")?;
+                let VentLine { start, end } = inspect::item(lines, index, "line")?;
+                Ok(format!("{},{} -> {},{}", start.x, start.y, end.x, end.y))
+            }
+            "vents" => {
+                let point = match args {
+                    [x, y] => x.parse().ok().zip(y.parse().ok()),
+                    _ => None,
+                };
+                let (x, y) = point.ok_or(InspectError::Usage("vents X Y"))?;
+                let map = VentMap::new(lines, true);
+                let count = map.vents.get(&Point::new(x, y)).copied().unwrap_or(0);
+                Ok(count.to_string())
+            }
+            "overlaps" => {
+                inspect::no_arguments(args, "overlaps")?;
+                Ok(format!(
+                    "{} without diagonals, {} with diagonals",
+                    VentMap::new(lines, false).get_multiple_vent_point_count(),
+                    VentMap::new(lines, true).get_multiple_vent_point_count()
+                ))
+            }
+            _ => Err(InspectError::UnknownCommand),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct VentMap {
+    vents: std::collections::HashMap<Point, usize>,
+}
+
+impl VentMap {
+    pub fn new(lines: &[VentLine], include_diagonal: bool) -> Self {
+        let mut map = VentMap::default();
+
+        for line in lines {
+            map.plot_line(line, include_diagonal);
+        }
+
+        map
+    }
+
+    fn plot_line(&mut self, line: &VentLine, include_diagonal: bool) {
+        let VentLine { start, end } = *line;
+
+        if start.y == end.y {
+            for point in horizontal_line(start, end) {
+                let vent = self.vents.entry(point).or_insert(0);
+                *vent += 1;
+            }
+        }
+
+        if start.x == end.x {
+            for point in vertical_line(start, end) {
+                let vent = self.vents.entry(point).or_insert(0);
+                *vent += 1;
+            }
+        }
+
+        if start.x != end.x && start.y != end.y && include_diagonal {
+            for point in diagonal_line(start, end) {
+                let vent = self.vents.entry(point).or_insert(0);
+                *vent += 1;
+            }
+        }
+    }
+
+    pub fn get_multiple_vent_point_count(&self) -> usize {
+        self.vents.values().filter(|&&count| count > 1).count()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VentLine {
+    start: Point,
+    end: Point,
+}
+
+impl VentLine {
+    /// Parses a `x1,y1 -> x2,y2` line, which must be a slice of `input`.
+    fn new(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (start, end) = parse::pair(DAY, input, line, "->")?;
+        let start = start.parse::<Point>().map_err(|e| e.within(input, start))?;
+        let end = end.parse::<Point>().map_err(|e| e.within(input, end))?;
+
+        let width = std::cmp::max(start.x, end.x) - std::cmp::min(start.x, end.x);
+        let height = std::cmp::max(start.y, end.y) - std::cmp::min(start.y, end.y);
+        if width != 0 && height != 0 && width != height {
+            return Err(ParseError::new(
+                DAY,
+                input,
+                line,
+                "expected a horizontal, vertical or 45 degree vent line",
+            ));
+        }
+        Ok(Self { start, end })
+    }
+}
+
+pub fn parse_vent_lines(input: &str) -> Result<Vec<VentLine>, ParseError> {
+    input
+        .lines()
+        .map(|line| VentLine::new(input, line))
+        .collect()
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+struct Point {
+    x: u16,
+    y: u16,
+}
+
+impl std::str::FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords: Vec<&str> = s.split(',').collect();
+        if coords.len() != 2 {
+            return Err(ParseError::new(DAY, s, s, "expected a point like x,y"));
+        }
+        let x = parse::number(DAY, s, coords[0], "an x coordinate")?;
+        let y = parse::number(DAY, s, coords[1], "a y coordinate")?;
+        Ok(Self { x, y })
+    }
+}
+
+impl Point {
+    fn new(x: u16, y: u16) -> Self {
+        Self { x, y }
+    }
+}
+
+fn horizontal_line(start: Point, end: Point) -> impl Iterator<Item = Point> {
+    assert_eq!(start.y, end.y);
+    let x1 = std::cmp::min(start.x, end.x);
+    let x2 = std::cmp::max(start.x, end.x);
+    let y = start.y;
+    (x1..=x2).map(move |x| Point::new(x, y))
+}
+
+fn vertical_line(start: Point, end: Point) -> impl Iterator<Item = Point> {
+    assert_eq!(start.x, end.x);
+    let y1 = std::cmp::min(start.y, end.y);
+    let y2 = std::cmp::max(start.y, end.y);
+    let x = start.x;
+    (y1..=y2).map(move |y| Point::new(x, y))
+}
+
+fn diagonal_line(start: Point, end: Point) -> impl Iterator<Item = Point> {
+    // Counted in a `u32`, as a line across the whole grid has 65536 points.
+    let len = u32::from(std::cmp::max(start.x, end.x) - std::cmp::min(start.x, end.x)) + 1;
+    let mut x = start.x;
+    let mut y = start.y;
+    (0..len).map(move |i| {
+        let point = Point::new(x, y);
+        if i < len - 1 {
+            if start.x > end.x {
+                x -= 1;
+            } else {
+                x += 1;
+            }
+            if start.y > end.y {
+                y -= 1;
+            } else {
+                y += 1;
+            }
+        }
+        point
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples::example_tests!(Day05);
+
+    const INPUT: &str = include_str!("../../assets/2021/examples/day_05.txt");
+
+    fn vent_map(input: &str, include_diagonal: bool) -> VentMap {
+        VentMap::new(&parse_vent_lines(input).unwrap(), include_diagonal)
+    }
+
+    #[test]
+    fn test_get_multiple_vent_point_count() {
+        let map = vent_map(INPUT, false);
+        assert_eq!(map.get_multiple_vent_point_count(), 5);
+    }
+
+    #[test]
+    fn test_get_multiple_vent_point_count_with_diagonal() {
+        let map = vent_map(INPUT, true);
+        assert_eq!(map.get_multiple_vent_point_count(), 12);
+    }
+
+    #[test]
+    fn test_horizontal_vent_line() {
+        let map = vent_map("6,0 -> 3,0", false);
+        assert!((3..7)
+            .map(|x| Point::new(x, 0))
+            .all(|point| map.vents.contains_key(&point)));
+    }
+
+    #[test]
+    fn test_vertical_vent_line() {
+        let map = vent_map("1,3 -> 1,5", false);
+        assert!((3..6)
+            .map(|y| Point::new(1, y))
+            .all(|point| map.vents.contains_key(&point)));
+    }
+
+    #[test]
+    fn test_diagonal_vent_line() {
+        let map = vent_map("0,2 -> 2,0", true);
+        assert!([Point::new(0, 2), Point::new(1, 1), Point::new(2, 0)]
+            .iter()
+            .all(|point| map.vents.contains_key(point)));
+    }
+
+    #[test]
+    fn test_overlapping_vent_lines_increases_vent_count() {
+        let map = vent_map(INPUT, false);
+        assert_eq!(map.vents[&Point::new(0, 9)], 2);
+    }
+
+    #[test]
+    fn test_invalid_coordinate() {
+        let error = parse_vent_lines("0,9 -> 5,9\n8,0 -> 0,y8").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 10));
+        assert_eq!(error.text, "y8");
+    }
+
+    #[test]
+    fn test_vent_lines_at_the_edge_of_the_grid() {
+        let map = vent_map(
+            "0,0 -> 65535,0\n65535,0 -> 65535,65535\n0,0 -> 65535,65535",
+            true,
+        );
+        assert_eq!(map.vents.len(), 3 * 65536 - 3);
+        assert_eq!(map.vents[&Point::new(65535, 65535)], 2);
+        assert_eq!(map.get_multiple_vent_point_count(), 3);
+    }
+
+    #[test]
+    fn test_coordinate_out_of_range() {
+        let error = parse_vent_lines("0,0 -> 65536,0").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 8));
+        assert_eq!(error.text, "65536");
+    }
+
+    #[test]
+    fn test_invalid_vent_line() {
+        assert!(parse_vent_lines("0,9 5,9").is_err());
+        assert!(parse_vent_lines("0,0 -> 2,1").is_err());
+    }
+
+    #[test]
+    fn test_inspect_vents() {
+        let lines = parse_vent_lines(INPUT).unwrap();
+        assert_eq!(
+            Day05.inspect(&lines, "vents", &["2", "2"]),
+            Ok(String::from("2"))
+        );
+        assert_eq!(
+            Day05.inspect(&lines, "vents", &["9", "9"]),
+            Ok(String::from("0"))
+        );
+        assert_eq!(
+            Day05.inspect(&lines, "line", &["4"]),
+            Ok(String::from("7,0 -> 7,4"))
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use crate::inspect::{self, Command, InspectError};
+use crate::parse::{self, ParseError};
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+use crate::trace;
+
+const DAY: u8 = 6;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "Lanternfish",
+    questions: [
+        "How many lanterfish would there be after 80 days?",
+        "How many lanterfish would there be after 256 days?",
+    ],
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Population;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<Population, ParseError> {
+        Population::new(input)
+    }
+
+    fn part_one(&self, population: &Population) -> String {
+        population.clone().simulate(80).to_string()
+    }
+
+    fn part_two(&self, population: &Population) -> String {
+        population.clone().simulate(256).to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "simulate DAYS",
+                description: "the number of lanternfish after DAYS days",
+            },
+            Command {
+                usage: "buckets",
+                description: "how many adult and baby fish share each timer",
+            },
+        ]
+    }
+
+    fn inspect(
+        &self,
+        population: &Population,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        match command {
+            "simulate" => {
+                let days = inspect::argument(args, "simulate DAYS")?;
+                Ok(population.clone().simulate(days).to_string())
+            }
+            "buckets" => {
+                inspect::no_arguments(args, "buckets")?;
+                Ok(format!(
+                    "adult {:?}, baby {:?}",
+                    population.adult_fish, population.baby_fish
+                ))
+            }
+            _ => Err(InspectError::UnknownCommand),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Population {
+    adult_fish: Vec<u128>,
+    baby_fish: Vec<u128>,
+    threshold: usize,
+    spawning: usize,
+}
+
+impl Default for Population {
+    fn default() -> Self {
+        let adult_fish = vec![0; 7];
+        let baby_fish = vec![0; 2];
+        Self {
+            adult_fish,
+            baby_fish,
+            threshold: 0,
+            spawning: 0,
+        }
+    }
+}
+
+impl Population {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut population = Population::default();
+        for s in input.trim().split(',') {
+            let fish = parse::number::<usize>(DAY, input, s.trim(), "a timer from 0 to 6")?;
+            if fish >= population.adult_fish.len() {
+                return Err(ParseError::new(
+                    DAY,
+                    input,
+                    s,
+                    "expected a timer from 0 to 6",
+                ));
+            }
+            population.adult_fish[fish] += 1;
+        }
+        Ok(population)
+    }
+
+    fn update(&mut self) {
+        let babies = self.adult_fish[self.spawning];
+        self.adult_fish[self.spawning] += self.baby_fish[self.threshold];
+        self.baby_fish[self.threshold] = babies;
+        self.threshold = (self.threshold + 1) % self.baby_fish.len();
+        self.spawning = (self.spawning + 1) % self.adult_fish.len();
+    }
+
+    pub fn simulate(&mut self, days: u16) -> u128 {
+        for day in 1..=days {
+            self.update();
+            trace::debug!(
+                "day {}: adult {:?}, baby {:?}",
+                day,
+                self.adult_fish,
+                self.baby_fish
+            );
+        }
+        self.baby_fish.iter().sum::<u128>() + self.adult_fish.iter().sum::<u128>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples::example_tests!(Day06);
+
+    const INPUT: &str = include_str!("../../assets/2021/examples/day_06.txt");
+
+    #[test]
+    fn test_simulate_18_days() {
+        let mut population = Population::new(INPUT).unwrap();
+        assert_eq!(population.simulate(18), 26);
+    }
+
+    #[test]
+    fn test_simulate_80_days() {
+        let mut population = Population::new(INPUT).unwrap();
+        assert_eq!(population.simulate(80), 5934);
+    }
+
+    #[test]
+    fn test_simulate_256_days() {
+        let mut population = Population::new(INPUT).unwrap();
+        assert_eq!(population.simulate(256), 26984457539);
+    }
+
+    #[test]
+    fn test_invalid_timer() {
+        let error = Population::new("3,4,9,1,2").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert_eq!(error.text, "9");
+    }
+
+    #[test]
+    fn test_inspect_simulate() {
+        let population = Population::new(INPUT).unwrap();
+        assert_eq!(
+            Day06.inspect(&population, "simulate", &["18"]),
+            Ok(String::from("26"))
+        );
+        assert!(Day06.inspect(&population, "simulate", &[]).is_err());
+    }
+
+    #[test]
+    fn test_trace_buckets() {
+        let mut population = Population::new(INPUT).unwrap();
+        let (_, lines) = trace::capture(trace::Level::Debug, || population.simulate(2));
+        assert_eq!(
+            lines,
+            [
+                "[debug y2021::day_06] day 1: adult [0, 1, 1, 2, 1, 0, 0], baby [0, 0]",
+                "[debug y2021::day_06] day 2: adult [0, 1, 1, 2, 1, 0, 0], baby [0, 1]",
+            ]
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use crate::inspect::{self, Command, InspectError};
+use crate::parse::{self, ParseError};
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+
+const DAY: u8 = 7;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "The Treachery of Whales",
+    questions: [
+        "Using constant fuel rate, determine the position with the cheapest fuel cost. How much fuel must they spend to align to that position?",
+        "Using actual fuel rate, determine the position with the cheapest fuel cost. How much fuel must they spend to align to that position",
+    ],
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = CrabFleet;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<CrabFleet, ParseError> {
+        CrabFleet::new(input)
+    }
+
+    fn part_one(&self, crabs: &CrabFleet) -> String {
+        crabs.get_cheapest_constant_fuel_cost().to_string()
+    }
+
+    fn part_two(&self, crabs: &CrabFleet) -> String {
+        crabs.get_cheapest_fuel_cost().to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "cost POSITION",
+                description: "fuel to align at POSITION at the constant and actual rate",
+            },
+            Command {
+                usage: "candidates",
+                description: "the mean and median positions the search runs between",
+            },
+        ]
+    }
+
+    fn inspect(
+        &self,
+        crabs: &CrabFleet,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        match command {
+            "cost" => {
+                let position = inspect::argument(args, "cost POSITION")?;
+                Ok(format!(
+                    "{} at the constant rate, {} at the actual rate",
+                    crabs.calculate_constant_fuel_cost(position),
+                    crabs.calculate_fuel_cost(position)
+                ))
+            }
+            "candidates" => {
+                inspect::no_arguments(args, "candidates")?;
+                Ok(format!(
+                    "mean {}, median {}",
+                    crabs.get_mean_position(),
+                    crabs.get_median_position()
+                ))
+            }
+            _ => Err(InspectError::UnknownCommand),
+        }
+    }
+}
+
+pub struct CrabFleet {
+    positions: Vec<i64>,
+}
+
+impl CrabFleet {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let positions = parse::number_list(DAY, input, input.trim(), ',', "a crab position")?;
+        Ok(Self { positions })
+    }
+
+    pub fn calculate_fuel_cost(&self, destination: i64) -> i64 {
+        self.positions
+            .iter()
+            .map(|p| {
+                let distance = (p - destination).abs();
+                distance * (distance + 1) / 2
+            })
+            .sum()
+    }
+
+    pub fn get_cheapest_fuel_cost(&self) -> i64 {
+        let mean = self.get_mean_position();
+        let median = self.get_median_position();
+        (std::cmp::min(mean, median)..=std::cmp::max(mean, median))
+            .map(|p| self.calculate_fuel_cost(p))
+            .min()
+            .unwrap()
+    }
+
+    pub fn calculate_constant_fuel_cost(&self, destination: i64) -> i64 {
+        self.positions.iter().map(|p| (p - destination).abs()).sum()
+    }
+
+    pub fn get_cheapest_constant_fuel_cost(&self) -> i64 {
+        let mean = self.get_mean_position();
+        let median = self.get_median_position();
+        (std::cmp::min(mean, median)..=std::cmp::max(mean, median))
+            .map(|p| self.calculate_constant_fuel_cost(p))
+            .min()
+            .unwrap()
+    }
+
+    fn get_median_position(&self) -> i64 {
+        let mut positions = self.positions.clone();
+        positions.sort();
+        positions[positions.len() / 2]
+    }
+
+    fn get_mean_position(&self) -> i64 {
+        (self.positions.iter().sum::<i64>() as f64 / self.positions.len() as f64).round() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples::example_tests!(Day07);
+
+    const INPUT: &str = include_str!("../../assets/2021/examples/day_07.txt");
+
+    #[test]
+    fn test_get_cheapest_fuel_cost() {
+        let crabs = CrabFleet::new(INPUT).unwrap();
+        assert_eq!(crabs.get_cheapest_fuel_cost(), 168);
+    }
+
+    #[test]
+    fn test_get_cheapest_constant_fuel_cost() {
+        let crabs = CrabFleet::new(INPUT).unwrap();
+        assert_eq!(crabs.get_cheapest_constant_fuel_cost(), 37);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let error = CrabFleet::new("").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    #[test]
+    fn test_inspect_cost() {
+        let crabs = CrabFleet::new(INPUT).unwrap();
+        assert_eq!(
+            Day07.inspect(&crabs, "cost", &["2"]),
+            Ok(String::from(
+                "37 at the constant rate, 206 at the actual rate"
+            ))
+        );
+    }
+}
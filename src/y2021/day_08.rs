@@ -0,0 +1,306 @@
+use crate::inspect::{self, Command, InspectError};
+use crate::parse::ParseError;
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+
+const DAY: u8 = 8;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "Seven Segment Search",
+    questions: [
+        "In the output values, how many times do digits 1, 4, 7, or 8 appear?",
+        "What do you get if you add up all of the output values?",
+    ],
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Display>;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<Display>, ParseError> {
+        input
+            .lines()
+            .map(|line| Display::new(line).map_err(|e| e.within(input, line)))
+            .collect()
+    }
+
+    fn part_one(&self, displays: &Vec<Display>) -> String {
+        displays
+            .iter()
+            .map(|display| display.get_unique_digit_count())
+            .sum::<usize>()
+            .to_string()
+    }
+
+    fn part_two(&self, displays: &Vec<Display>) -> String {
+        displays
+            .iter()
+            .map(|display| display.get_output_value())
+            .sum::<u64>()
+            .to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[Command {
+            usage: "display INDEX",
+            description: "display INDEX with its easy digit count and output value",
+        }]
+    }
+
+    fn inspect(
+        &self,
+        displays: &Vec<Display>,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        match command {
+            "display" => {
+                let index = inspect::argument(args, "display INDEX")?;
+                let display = inspect::item(displays, index, "display")?;
+                Ok(format!(
+                    "{}\n{} easy digits, output value {}",
+                    display,
+                    display.get_unique_digit_count(),
+                    display.get_output_value()
+                ))
+            }
+            _ => Err(InspectError::UnknownCommand),
+        }
+    }
+}
+
+pub struct Display {
+    patterns: Vec<String>,
+    outputs: Vec<String>,
+}
+
+impl Display {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (patterns, outputs) = input.split_once('|').ok_or_else(|| {
+            ParseError::new(
+                DAY,
+                input,
+                input,
+                "expected ten signal patterns and four output digits separated by |",
+            )
+        })?;
+        let patterns = Self::patterns(input, patterns.trim(), 10)?;
+        let outputs = Self::patterns(input, outputs.trim(), 4)?;
+
+        let wires: Vec<char> = patterns.iter().flat_map(|s| s.chars()).collect();
+        let mut digits = Vec::with_capacity(patterns.len());
+        for pattern in patterns.iter() {
+            match Self::decode(&wires, pattern) {
+                Some(digit) if !digits.contains(&digit) => digits.push(digit),
+                _ => {
+                    return Err(ParseError::new(
+                        DAY,
+                        input,
+                        pattern,
+                        "expected the ten patterns to light up the digits 0 to 9",
+                    ))
+                }
+            }
+        }
+        if let Some(output) = outputs.iter().find(|output| {
+            !patterns.iter().any(|pattern| {
+                pattern.len() == output.len() && pattern.chars().all(|c| output.contains(c))
+            })
+        }) {
+            return Err(ParseError::new(
+                DAY,
+                input,
+                output,
+                "expected one of the ten signal patterns",
+            ));
+        }
+
+        Ok(Self {
+            patterns: patterns.into_iter().map(String::from).collect(),
+            outputs: outputs.into_iter().map(String::from).collect(),
+        })
+    }
+
+    /// Parses the `expected` whitespace separated patterns in `part`, a slice
+    /// of `input`.
+    fn patterns<'a>(
+        input: &str,
+        part: &'a str,
+        expected: usize,
+    ) -> Result<Vec<&'a str>, ParseError> {
+        let patterns: Vec<&str> = part.split_whitespace().collect();
+        if patterns.len() != expected {
+            return Err(ParseError::new(
+                DAY,
+                input,
+                part,
+                format!("expected {} patterns, found {}", expected, patterns.len()),
+            ));
+        }
+        if let Some(pattern) = patterns
+            .iter()
+            .find(|pattern| !pattern.chars().all(|c| ('a'..='g').contains(&c)))
+        {
+            return Err(ParseError::new(
+                DAY,
+                input,
+                pattern,
+                "expected segments a to g",
+            ));
+        }
+        Ok(patterns)
+    }
+
+    /// Identifies the digit `pattern` shows by how often each of its segments
+    /// is used across all ten patterns, listed in `wires`.
+    fn decode(wires: &[char], pattern: &str) -> Option<u64> {
+        match wires.iter().filter(|&&w| pattern.contains(w)).count() {
+            42 => Some(0),
+            17 => Some(1),
+            34 => Some(2),
+            39 => Some(3),
+            30 => Some(4),
+            37 => Some(5),
+            41 => Some(6),
+            25 => Some(7),
+            49 => Some(8),
+            45 => Some(9),
+            _ => None,
+        }
+    }
+
+    pub fn get_unique_digit_count(&self) -> usize {
+        self.outputs
+            .iter()
+            .filter(|s| [2usize, 3usize, 4usize, 7usize].contains(&s.len()))
+            .count()
+    }
+
+    pub fn get_output_value(&self) -> u64 {
+        let wires: Vec<char> = self.patterns.iter().flat_map(|s| s.chars()).collect();
+        let digits: Vec<u64> = self
+            .outputs
+            .iter()
+            .map(|s| Self::decode(&wires, s).unwrap_or_else(|| panic!("unknown digit: {}", s)))
+            .collect();
+        digits[0] * 1000 + digits[1] * 100 + digits[2] * 10 + digits[3]
+    }
+}
+
+impl std::fmt::Display for Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {}",
+            self.patterns.join(" "),
+            self.outputs.join(" ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples::example_tests!(Day08);
+
+    const INPUT: &str = include_str!("../../assets/2021/examples/day_08.txt");
+
+    #[test]
+    fn test() {
+        let count = INPUT
+            .lines()
+            .flat_map(|line| line.split(" | ").nth(1).unwrap().split_whitespace())
+            .filter(|&s| [2usize, 3usize, 4usize, 7usize].contains(&s.len()))
+            .count();
+        assert_eq!(count, 26);
+    }
+
+    #[test]
+    fn test_display_total() {
+        let display_total: u64 = INPUT
+            .lines()
+            .map(|s| {
+                let display = Display::new(s).unwrap();
+                display.get_output_value()
+            })
+            .sum();
+        assert_eq!(display_total, 61229)
+    }
+
+    #[test]
+    fn test_display_output_value() {
+        let display = Display::new(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf",
+        )
+        .unwrap();
+        assert_eq!(display.get_output_value(), 5353)
+    }
+
+    #[test]
+    fn test_invalid_display() {
+        let input = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb\n";
+        let error = Day08.parse(input).err().unwrap();
+        assert_eq!((error.line, error.column), (1, 62));
+    }
+
+    #[test]
+    fn test_invalid_segment() {
+        let error = Display::new(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfxb cdbaf",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.text, "cdfxb");
+    }
+
+    #[test]
+    fn test_patterns_without_every_digit() {
+        let error = Display::new(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb dab | cdfeb fcadb cdfeb cdbaf",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.message,
+            "expected the ten patterns to light up the digits 0 to 9"
+        );
+    }
+
+    #[test]
+    fn test_output_not_among_patterns() {
+        let error = Display::new(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb aa cdbaf",
+        )
+        .err()
+        .unwrap();
+        assert_eq!((error.text.as_str(), error.column), ("aa", 74));
+    }
+
+    #[test]
+    fn test_inspect_display() {
+        let displays = Day08.parse(INPUT).unwrap();
+        let output = Day08.inspect(&displays, "display", &["0"]).unwrap();
+        assert!(output.ends_with("2 easy digits, output value 8394"));
+    }
+
+    #[test]
+    fn test_extra_spaces_around_separator() {
+        let display = Display::new(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb  ab\t|   cdfeb fcadb cdfeb  cdbaf",
+        )
+        .unwrap();
+        assert_eq!(display.get_output_value(), 5353);
+        assert_eq!(
+            display.to_string(),
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf"
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use crate::grid::Grid;
+use crate::inspect::{self, Command, InspectError};
+use crate::parse::{self, ParseError};
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+
+const DAY: u8 = 9;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "Smoke Basin",
+    questions: [
+        "What is the sum of the risk levels of all low points on your heightmap?",
+        "What do you get if you multiply together the sizes of the three largest basins?",
+    ],
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = HeightMap;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<HeightMap, ParseError> {
+        HeightMap::new(input)
+    }
+
+    fn part_one(&self, map: &HeightMap) -> String {
+        map.get_risk_level().to_string()
+    }
+
+    fn part_two(&self, map: &HeightMap) -> String {
+        let mut basins = map.get_basins();
+        basins.sort_by_key(|b| b.len());
+        basins
+            .iter()
+            .rev()
+            .take(3)
+            .map(|b| b.len())
+            .product::<usize>()
+            .to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "neighbors INDEX",
+                description: "(index, height) of the cells next to cell INDEX",
+            },
+            Command {
+                usage: "low-points",
+                description: "(index, height) of every low point",
+            },
+            Command {
+                usage: "basins",
+                description: "basin sizes, largest first",
+            },
+        ]
+    }
+
+    fn inspect(
+        &self,
+        map: &HeightMap,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        match command {
+            "neighbors" => {
+                let index = inspect::argument(args, "neighbors INDEX")?;
+                if index >= map.grid.len() {
+                    return Err(InspectError::Invalid(format!(
+                        "no cell {}, there are {}",
+                        index,
+                        map.grid.len()
+                    )));
+                }
+                Ok(format!("{:?}", map.get_neighbors(index)))
+            }
+            "low-points" => {
+                inspect::no_arguments(args, "low-points")?;
+                Ok(format!("{:?}", map.get_low_points()))
+            }
+            "basins" => {
+                inspect::no_arguments(args, "basins")?;
+                let mut sizes: Vec<usize> = map.get_basins().iter().map(|b| b.len()).collect();
+                sizes.sort_by(|a, b| b.cmp(a));
+                Ok(format!("{:?}", sizes))
+            }
+            _ => Err(InspectError::UnknownCommand),
+        }
+    }
+}
+
+pub struct HeightMap {
+    grid: Grid<u32>,
+}
+
+impl HeightMap {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = parse::digit_grid(DAY, input)?;
+        Ok(Self { grid })
+    }
+
+    pub fn get_neighbors(&self, index: usize) -> Vec<(usize, u32)> {
+        let (row, column) = self.grid.position_of(index);
+        self.grid
+            .neighbors(row, column)
+            .map(|position| (self.grid.index_of(position), self.grid[position]))
+            .collect()
+    }
+
+    pub fn get_low_points(&self) -> Vec<(usize, u32)> {
+        self.grid
+            .iter()
+            .enumerate()
+            .filter(|(i, v)| self.get_neighbors(*i).iter().all(|(_, nv)| *v < nv))
+            .map(|(i, &v)| (i, v))
+            .collect()
+    }
+
+    pub fn get_risk_level(&self) -> u32 {
+        self.get_low_points().iter().map(|(_, v)| *v + 1).sum()
+    }
+
+    pub fn get_basins(&self) -> Vec<std::collections::HashSet<(usize, u32)>> {
+        let mut basins = Vec::new();
+        for low_point in self.get_low_points() {
+            let mut queue = std::collections::HashSet::new();
+            let mut basin = std::collections::HashSet::new();
+            queue.insert(low_point);
+
+            while !queue.is_empty() {
+                let tile = queue.iter().copied().next().unwrap();
+                queue.remove(&tile);
+                if !basin.contains(&tile) {
+                    basin.insert(tile);
+                }
+                for neighbor in self.get_neighbors(tile.0) {
+                    if neighbor.1 < 9 && !basin.contains(&neighbor) {
+                        queue.insert(neighbor);
+                    }
+                }
+            }
+            basins.push(basin);
+        }
+        basins
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    crate::examples::example_tests!(Day09);
+
+    const INPUT: &str = include_str!("../../assets/2021/examples/day_09.txt");
+
+    #[test]
+    fn test_get_risk_level() {
+        let map = HeightMap::new(INPUT).unwrap();
+        assert_eq!(map.get_risk_level(), 15);
+    }
+
+    #[test]
+    fn test_get_basins() {
+        let map = HeightMap::new(INPUT).unwrap();
+        let mut basins = map.get_basins();
+        basins.sort_by_key(|b| b.len());
+        let value = basins
+            .iter()
+            .rev()
+            .take(3)
+            .map(|b| b.len())
+            .product::<usize>();
+        assert_eq!(value, 1134);
+    }
+
+    #[test]
+    fn test_get_low_points() {
+        let map = HeightMap::new(INPUT).unwrap();
+        let expected = vec![(1, 1), (9, 0), (22, 5), (46, 5)];
+        assert_eq!(map.get_low_points(), expected);
+    }
+
+    #[test]
+    fn test_get_neighbors() {
+        let map = HeightMap::new(INPUT).unwrap();
+        let expected = vec![(3, 9), (12, 8), (14, 8), (23, 6)];
+        assert_eq!(map.get_neighbors(13), expected);
+    }
+
+    #[test]
+    fn test_get_neighbors_top_edge() {
+        let map = HeightMap::new(INPUT).unwrap();
+        let expected = vec![(4, 9), (6, 3), (15, 9)];
+        assert_eq!(map.get_neighbors(5), expected);
+    }
+
+    #[test]
+    fn test_get_neighbors_bottom_edge() {
+        let map = HeightMap::new(INPUT).unwrap();
+        let expected = vec![(36, 6), (45, 6), (47, 6)];
+        assert_eq!(map.get_neighbors(46), expected);
+    }
+
+    #[test]
+    fn test_get_neighbors_left_edge() {
+        let map = HeightMap::new(INPUT).unwrap();
+        let expected = vec![(10, 3), (21, 8), (30, 8)];
+        assert_eq!(map.get_neighbors(20), expected);
+    }
+
+    #[test]
+    fn test_get_neighbors_right_edge() {
+        let map = HeightMap::new(INPUT).unwrap();
+        let expected = vec![(9, 0), (18, 2), (29, 2)];
+        assert_eq!(map.get_neighbors(19), expected);
+    }
+
+    #[test]
+    fn test_get_neighbors_corner() {
+        let map = HeightMap::new(INPUT).unwrap();
+        let expected = vec![(30, 8), (41, 8)];
+        assert_eq!(map.get_neighbors(40), expected);
+    }
+
+    #[test]
+    fn test_invalid_height() {
+        let error = HeightMap::new("2199\n39x7").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn test_uneven_rows() {
+        let error = HeightMap::new("2199\n398").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_inspect_neighbors() {
+        let map = HeightMap::new(INPUT).unwrap();
+        assert_eq!(
+            Day09.inspect(&map, "neighbors", &["13"]),
+            Ok(String::from("[(3, 9), (12, 8), (14, 8), (23, 6)]"))
+        );
+        assert!(Day09.inspect(&map, "neighbors", &["50"]).is_err());
+    }
+
+    #[test]
+    fn test_inspect_low_points() {
+        let map = HeightMap::new(INPUT).unwrap();
+        assert_eq!(
+            Day09.inspect(&map, "low-points", &[]),
+            Ok(String::from("[(1, 1), (9, 0), (22, 5), (46, 5)]"))
+        );
+    }
+
+    #[test]
+    fn test_trailing_spaces_and_blank_lines() {
+        let input = INPUT.replace('\n', "  \n") + "\n\n";
+        let answers = crate::runner::solve(&Day09, &input, &Part::ALL).unwrap();
+        assert_eq!(answers.answers[0].answer, "15");
+        assert_eq!(answers.answers[1].answer, "1134");
+    }
+}
@@ -0,0 +1,295 @@
+use crate::inspect::{self, Command, InspectError};
+use crate::parse::ParseError;
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+use crate::trace;
+
+const DAY: u8 = 10;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "Syntax Scoring",
+    questions: [
+        "What is the total syntax error score for those errors?",
+        "What is the middle score?",
+    ],
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = String;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<String, ParseError> {
+        SyntaxChecker::validate(input)?;
+        Ok(input.to_string())
+    }
+
+    fn part_one(&self, input: &String) -> String {
+        SyntaxChecker::get_syntax_error_score(input).to_string()
+    }
+
+    fn part_two(&self, input: &String) -> String {
+        SyntaxChecker::get_autocomplete_score(input).to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[Command {
+            usage: "line INDEX",
+            description: "whether line INDEX is corrupted, or how it completes",
+        }]
+    }
+
+    fn inspect(
+        &self,
+        input: &String,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        match command {
+            "line" => {
+                let index = inspect::argument(args, "line INDEX")?;
+                let lines: Vec<&str> = input.lines().collect();
+                let line = inspect::item(&lines, index, "line")?;
+                Ok(match SyntaxChecker::parse_chunk(line) {
+                    Ok(completion) => format!("incomplete, completed by {}", completion),
+                    Err(e) => format!("corrupted, {}", e),
+                })
+            }
+            _ => Err(InspectError::UnknownCommand),
+        }
+    }
+}
+
+pub struct SyntaxChecker {}
+
+#[derive(Debug)]
+pub struct SyntaxError {
+    token: char,
+    message: String,
+}
+
+impl std::fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SyntaxError {}
+
+impl SyntaxError {
+    /// The illegal token that stopped the chunk from parsing.
+    pub fn token(&self) -> char {
+        self.token
+    }
+}
+
+impl SyntaxChecker {
+    /// Checks that every line holds only brackets, and that at least one line
+    /// is incomplete rather than corrupted, so it has an autocomplete score.
+    fn validate(input: &str) -> Result<(), ParseError> {
+        if input.lines().next().is_none() {
+            return Err(ParseError::new(
+                DAY,
+                input,
+                input,
+                "expected a line of brackets",
+            ));
+        }
+        for line in input.lines() {
+            if let Some((i, c)) = line.char_indices().find(|&(_, c)| !"()[]{}<>".contains(c)) {
+                return Err(ParseError::new(
+                    DAY,
+                    input,
+                    &line[i..i + c.len_utf8()],
+                    "expected one of ()[]{}<>",
+                ));
+            }
+        }
+        if !input.lines().any(|line| Self::parse_chunk(line).is_ok()) {
+            return Err(ParseError::new(
+                DAY,
+                input,
+                &input[input.len()..],
+                "expected an incomplete line, but every line is corrupted",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn get_syntax_error_score(input: &str) -> u64 {
+        let mut illegal_tokens = Vec::new();
+        for chunk in input.lines() {
+            if let Err(e) = Self::parse_chunk(chunk) {
+                illegal_tokens.push(e.token);
+            }
+        }
+        illegal_tokens
+            .iter()
+            .map(|&c| match c {
+                ')' => 3,
+                ']' => 57,
+                '}' => 1197,
+                '>' => 25137,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    pub fn get_autocomplete_score(input: &str) -> u64 {
+        let mut scores = Vec::new();
+        for chunk in input.lines() {
+            if let Ok(completion) = Self::parse_chunk(chunk) {
+                scores.push(completion.chars().fold(0, |acc, c| {
+                    acc * 5
+                        + match c {
+                            ')' => 1,
+                            ']' => 2,
+                            '}' => 3,
+                            '>' => 4,
+                            _ => 0,
+                        }
+                }));
+            }
+        }
+        scores.sort();
+        scores[(scores.len() - 1) / 2]
+    }
+
+    pub fn parse_chunk(chunk: &str) -> Result<String, SyntaxError> {
+        let mut stack = Vec::new();
+        for token in chunk.chars() {
+            match token {
+                '(' | '[' | '{' | '<' => {
+                    stack.push(token);
+                }
+                ')' | ']' | '}' | '>' => {
+                    let open_token = match stack.last() {
+                        Some(&open_token) => open_token,
+                        None => {
+                            return Err(SyntaxError {
+                                token,
+                                message: format!("unexpected {}", token),
+                            })
+                        }
+                    };
+                    let close_token = Self::get_closing_token(open_token);
+                    if token == close_token {
+                        let _ = stack.pop();
+                    } else {
+                        return Err(SyntaxError {
+                            token,
+                            message: format!(
+                                "expected {}, but found {} instead",
+                                close_token, token
+                            ),
+                        });
+                    }
+                }
+                _ => {
+                    return Err(SyntaxError {
+                        token,
+                        message: format!("invalid token: {}", token),
+                    })
+                }
+            }
+            trace::trace!("{} leaves {}", token, stack.iter().collect::<String>());
+        }
+        Ok(stack
+            .iter()
+            .rev()
+            .map(|&c| Self::get_closing_token(c))
+            .collect::<String>())
+    }
+
+    fn get_closing_token(token: char) -> char {
+        match token {
+            '(' => ')',
+            '[' => ']',
+            '{' => '}',
+            '<' => '>',
+            _ => panic!("invalid token: {}", token),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples::example_tests!(Day10);
+
+    const INPUT: &str = include_str!("../../assets/2021/examples/day_10.txt");
+
+    #[test]
+    fn test_syntax_error_score() {
+        assert_eq!(SyntaxChecker::get_syntax_error_score(INPUT), 26397);
+    }
+
+    #[test]
+    fn test_autocomplete_score() {
+        assert_eq!(SyntaxChecker::get_autocomplete_score(INPUT), 288957);
+    }
+
+    #[test]
+    fn test_validate_invalid_token() {
+        let error = SyntaxChecker::validate("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]x")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 11));
+    }
+
+    #[test]
+    fn test_validate_empty_input() {
+        let error = SyntaxChecker::validate("").err().unwrap();
+        assert_eq!(error.message, "expected a line of brackets");
+    }
+
+    #[test]
+    fn test_validate_only_corrupted_lines() {
+        let error = SyntaxChecker::validate(
+            "(]
+{()()()>
+",
+        )
+        .err()
+        .unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+
+    #[test]
+    fn test_unexpected_closing_token() {
+        assert_eq!(SyntaxChecker::parse_chunk("()]").unwrap_err().token, ']');
+    }
+
+    #[test]
+    fn test_inspect_line() {
+        let input = String::from(INPUT);
+        assert_eq!(
+            Day10.inspect(&input, "line", &["0"]),
+            Ok(String::from("incomplete, completed by }}]])})]"))
+        );
+        assert_eq!(
+            Day10.inspect(&input, "line", &["2"]),
+            Ok(String::from("corrupted, expected ], but found } instead"))
+        );
+    }
+
+    #[test]
+    fn test_trace_stack() {
+        let (_, lines) = trace::capture(trace::Level::Trace, || SyntaxChecker::parse_chunk("[<>"));
+        assert_eq!(
+            lines,
+            [
+                "[trace y2021::day_10] [ leaves [",
+                "[trace y2021::day_10] < leaves [<",
+                "[trace y2021::day_10] > leaves [",
+            ]
+        );
+    }
+}
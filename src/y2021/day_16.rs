@@ -0,0 +1,616 @@
+use crate::inspect::{Command, InspectError};
+use crate::parse::ParseError;
+use crate::puzzle::PuzzleInfo;
+use crate::solution::Solution;
+use crate::trace;
+
+const DAY: u8 = 16;
+
+const INFO: PuzzleInfo = PuzzleInfo {
+    year: super::YEAR,
+    day: DAY,
+    title: "Packet Decoder",
+    questions: [
+        "What do you get if you add up the version numbers in all packets?",
+        "What do you get if you evaluate the expression represented by your hexadecimal-encoded BITS transmission?",
+    ],
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+
+    fn info(&self) -> &'static PuzzleInfo {
+        &INFO
+    }
+
+    fn parse(&self, input: &str) -> Result<Packet, ParseError> {
+        Decoder::parse(input)
+    }
+
+    fn part_one(&self, packet: &Packet) -> String {
+        packet.get_version_sum().to_string()
+    }
+
+    fn part_two(&self, packet: &Packet) -> String {
+        packet.evaluate().to_string()
+    }
+
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "packet [PATH]",
+                description: "the packet tree, or the sub-packet at a path such as 0.2",
+            },
+            Command {
+                usage: "packet.evaluate [PATH]",
+                description: "the value of the packet or sub-packet",
+            },
+            Command {
+                usage: "packet.version-sum [PATH]",
+                description: "the version sum of the packet or sub-packet",
+            },
+        ]
+    }
+
+    fn inspect(
+        &self,
+        packet: &Packet,
+        command: &str,
+        args: &[&str],
+    ) -> Result<String, InspectError> {
+        let usage = match command {
+            "packet" => "packet [PATH]",
+            "packet.evaluate" => "packet.evaluate [PATH]",
+            "packet.version-sum" => "packet.version-sum [PATH]",
+            _ => return Err(InspectError::UnknownCommand),
+        };
+        let packet = match args {
+            [] => packet,
+            [path] => packet
+                .sub_packet(path)
+                .ok_or_else(|| InspectError::Invalid(format!("no sub-packet at {}", path)))?,
+            _ => return Err(InspectError::Usage(usage)),
+        };
+        Ok(match command {
+            "packet" => packet.to_string(),
+            "packet.evaluate" => packet.evaluate().to_string(),
+            _ => packet.get_version_sum().to_string(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Packet {
+    version: u8,
+    type_id: u8,
+    data: PacketData,
+}
+
+impl Packet {
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    pub fn type_id(&self) -> u8 {
+        self.type_id
+    }
+
+    pub fn data(&self) -> &PacketData {
+        &self.data
+    }
+
+    pub fn get_version_sum(&self) -> u64 {
+        let mut sum = self.version as u64;
+        if let PacketData::Operator { sub_packets } = &self.data {
+            sum += sub_packets.iter().map(|p| p.get_version_sum()).sum::<u64>();
+        }
+        sum
+    }
+
+    /// Follows a dot separated path of sub-packet indices, such as `0.2`.
+    pub fn sub_packet(&self, path: &str) -> Option<&Packet> {
+        path.split('.').try_fold(self, |packet, index| {
+            let index = index.parse::<usize>().ok()?;
+            match &packet.data {
+                PacketData::Operator { sub_packets } => sub_packets.get(index),
+                PacketData::Literal(_) => None,
+            }
+        })
+    }
+
+    fn operator_name(&self) -> &'static str {
+        match self.type_id {
+            0 => "sum",
+            1 => "product",
+            2 => "minimum",
+            3 => "maximum",
+            5 => "greater than",
+            6 => "less than",
+            7 => "equal to",
+            _ => "unknown operator",
+        }
+    }
+
+    fn write_tree(&self, f: &mut std::fmt::Formatter, depth: usize) -> std::fmt::Result {
+        write!(
+            f,
+            "{:indent$}version {}, ",
+            "",
+            self.version,
+            indent = depth * 2
+        )?;
+        match &self.data {
+            PacketData::Literal(value) => write!(f, "literal {}", value),
+            PacketData::Operator { sub_packets } => {
+                write!(f, "{}", self.operator_name())?;
+                for packet in sub_packets {
+                    writeln!(f)?;
+                    packet.write_tree(f, depth + 1)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// The value of the packet. [`Decoder::parse`] rejects packets whose
+    /// value does not fit in a `u64`.
+    pub fn evaluate(&self) -> u64 {
+        self.checked_evaluate()
+            .expect("packet values are checked when decoded")
+    }
+
+    /// The value of the packet, or `None` if a sum or product anywhere in it
+    /// does not fit in a `u64`.
+    fn checked_evaluate(&self) -> Option<u64> {
+        let sub_packets = match &self.data {
+            PacketData::Literal(value) => return Some(*value),
+            PacketData::Operator { sub_packets } => sub_packets,
+        };
+        let values = sub_packets
+            .iter()
+            .map(|p| p.checked_evaluate())
+            .collect::<Option<Vec<u64>>>()?;
+        match self.type_id {
+            0 => values
+                .into_iter()
+                .try_fold(0u64, |acc, value| acc.checked_add(value)),
+            1 => values
+                .into_iter()
+                .try_fold(1u64, |acc, value| acc.checked_mul(value)),
+            2 => values.into_iter().min(),
+            3 => values.into_iter().max(),
+            5 => Some(u64::from(values[0] > values[1])),
+            6 => Some(u64::from(values[0] < values[1])),
+            7 => Some(u64::from(values[0] == values[1])),
+            _ => panic!("invalid operator: {}", self.type_id),
+        }
+    }
+}
+
+/// Shows the packet as a tree, one packet per line, indented by depth.
+impl std::fmt::Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.write_tree(f, 0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PacketData {
+    Literal(u64),
+    Operator { sub_packets: Vec<Packet> },
+}
+
+pub struct Decoder {}
+
+/// How deeply operator packets may nest. Puzzle inputs stay far below this,
+/// and it keeps a hostile transmission from overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+/// A decoding failure at a bit offset into the transmission.
+#[derive(Debug)]
+struct BitError {
+    index: usize,
+    message: &'static str,
+}
+
+impl Decoder {
+    pub fn parse(input: &str) -> Result<Packet, ParseError> {
+        let hex = input.trim();
+        let mut bits = String::with_capacity(hex.len() * 4);
+        for (i, c) in hex.char_indices() {
+            let value = c.to_digit(16).ok_or_else(|| {
+                ParseError::new(
+                    DAY,
+                    input,
+                    &hex[i..i + c.len_utf8()],
+                    "expected a hexadecimal digit",
+                )
+            })?;
+            bits += &format!("{:04b}", value);
+        }
+        let (packet, _) = Self::parse_packet(&bits, 0, 0).map_err(|e| {
+            let digit = e.index / 4;
+            let text = hex.get(digit..digit + 1).unwrap_or(&hex[hex.len()..]);
+            ParseError::new(
+                DAY,
+                input,
+                text,
+                format!("{} at bit {}", e.message, e.index),
+            )
+        })?;
+        Ok(packet)
+    }
+
+    fn read_bits(bits: &str, index: usize, len: usize) -> Result<&str, BitError> {
+        bits.get(index..index + len).ok_or(BitError {
+            index: bits.len(),
+            message: "transmission ends in the middle of a packet",
+        })
+    }
+
+    /// Parses the packet at bit `index`, which sits inside `depth` operator
+    /// packets.
+    fn parse_packet(bits: &str, index: usize, depth: usize) -> Result<(Packet, usize), BitError> {
+        let mut i = index;
+        let version = u8::from_str_radix(Self::read_bits(bits, i, 3)?, 2).unwrap();
+        i += 3;
+        let type_id = u8::from_str_radix(Self::read_bits(bits, i, 3)?, 2).unwrap();
+        i += 3;
+        trace::debug!("bit {}: version {}, type {}", index, version, type_id);
+        let (data, i) = match type_id {
+            4 => Self::parse_literal(bits, i)?,
+            _ => Self::parse_operator(bits, i, depth)?,
+        };
+        if let PacketData::Operator { sub_packets } = &data {
+            match type_id {
+                0..=3 if sub_packets.is_empty() => {
+                    return Err(BitError {
+                        index,
+                        message: "a sum, product, minimum or maximum packet needs sub-packets",
+                    })
+                }
+                5..=7 if sub_packets.len() != 2 => {
+                    return Err(BitError {
+                        index,
+                        message: "a comparison packet needs exactly two sub-packets",
+                    })
+                }
+                _ => {}
+            }
+        }
+        let packet = Packet {
+            version,
+            type_id,
+            data,
+        };
+        if packet.checked_evaluate().is_none() {
+            return Err(BitError {
+                index,
+                message: "the value of the packet does not fit in 64 bits",
+            });
+        }
+        trace::trace!("bits {}..{}: packet ends", index, i);
+        Ok((packet, i))
+    }
+
+    fn parse_literal(bits: &str, index: usize) -> Result<(PacketData, usize), BitError> {
+        let mut i = index;
+        let mut literal_bits = String::new();
+        loop {
+            let group = Self::read_bits(bits, i, 5)?;
+            literal_bits += &group[1..];
+            i += 5;
+            if literal_bits.trim_start_matches('0').len() > 64 {
+                return Err(BitError {
+                    index,
+                    message: "literal value does not fit in 64 bits",
+                });
+            }
+            if &group[..1] == "0" {
+                break;
+            }
+        }
+        Ok((
+            PacketData::Literal(u64::from_str_radix(&literal_bits, 2).unwrap()),
+            i,
+        ))
+    }
+
+    fn parse_operator(
+        bits: &str,
+        index: usize,
+        depth: usize,
+    ) -> Result<(PacketData, usize), BitError> {
+        if depth >= MAX_DEPTH {
+            return Err(BitError {
+                index,
+                message: "operator packets are nested too deeply",
+            });
+        }
+        let mut i = index;
+        let length_type_id = Self::read_bits(bits, i, 1)?;
+        i += 1;
+
+        let mut sub_packets = Vec::new();
+        if length_type_id == "0" {
+            let sub_packet_bit_len =
+                usize::from_str_radix(Self::read_bits(bits, i, 15)?, 2).unwrap();
+            i += 15;
+
+            let end = index + 16 + sub_packet_bit_len;
+            while i < end {
+                let (packet, new_i) = Self::parse_packet(bits, i, depth + 1)?;
+                if new_i > end {
+                    return Err(BitError {
+                        index: i,
+                        message: "sub-packet runs past the length of its operator packet",
+                    });
+                }
+                sub_packets.push(packet);
+                i = new_i;
+            }
+        } else {
+            let sub_packet_len = usize::from_str_radix(Self::read_bits(bits, i, 11)?, 2).unwrap();
+            i += 11;
+
+            for _ in 0..sub_packet_len {
+                let (packet, new_i) = Self::parse_packet(bits, i, depth + 1)?;
+                sub_packets.push(packet);
+                i = new_i;
+            }
+        }
+
+        Ok((PacketData::Operator { sub_packets }, i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples::example_tests!(Day16);
+
+    #[test]
+    fn test_parse_version() {
+        let packet = Decoder::parse("D2FE28").unwrap();
+        assert_eq!(packet.version, 6);
+    }
+
+    #[test]
+    fn test_parse_literal() {
+        let packet = Decoder::parse("D2FE28").unwrap();
+        assert_eq!(packet.data, PacketData::Literal(2021));
+    }
+
+    #[test]
+    fn test_parse_operator_with_length_type_id_0() {
+        let packet = Decoder::parse("38006F45291200").unwrap();
+        let expected = PacketData::Operator {
+            sub_packets: vec![
+                Packet {
+                    version: 6,
+                    type_id: 4,
+                    data: PacketData::Literal(10),
+                },
+                Packet {
+                    version: 2,
+                    type_id: 4,
+                    data: PacketData::Literal(20),
+                },
+            ],
+        };
+        assert_eq!(packet.data, expected);
+    }
+
+    #[test]
+    fn test_parse_operator_with_length_type_id_1() {
+        let packet = Decoder::parse("EE00D40C823060").unwrap();
+        let expected = PacketData::Operator {
+            sub_packets: vec![
+                Packet {
+                    version: 2,
+                    type_id: 4,
+                    data: PacketData::Literal(1),
+                },
+                Packet {
+                    version: 4,
+                    type_id: 4,
+                    data: PacketData::Literal(2),
+                },
+                Packet {
+                    version: 1,
+                    type_id: 4,
+                    data: PacketData::Literal(3),
+                },
+            ],
+        };
+        assert_eq!(packet.data, expected);
+    }
+
+    #[test]
+    fn test_packet_get_version_sum() {
+        let packet = Decoder::parse("8A004A801A8002F478").unwrap();
+        assert_eq!(packet.get_version_sum(), 16);
+    }
+
+    #[test]
+    fn test_packet_evaluate_sum_operator() {
+        let packet = Decoder::parse("C200B40A82").unwrap();
+        assert_eq!(packet.evaluate(), 3)
+    }
+
+    #[test]
+    fn test_packet_evaluate_product_operator() {
+        let packet = Decoder::parse("04005AC33890").unwrap();
+        assert_eq!(packet.evaluate(), 54)
+    }
+
+    #[test]
+    fn test_packet_evaluate_minimum_operator() {
+        let packet = Decoder::parse("880086C3E88112").unwrap();
+        assert_eq!(packet.evaluate(), 7)
+    }
+
+    #[test]
+    fn test_packet_evaluate_maximum_operator() {
+        let packet = Decoder::parse("CE00C43D881120").unwrap();
+        assert_eq!(packet.evaluate(), 9)
+    }
+
+    #[test]
+    fn test_packet_evaluate_less_than_operator() {
+        let packet = Decoder::parse("D8005AC2A8F0").unwrap();
+        assert_eq!(packet.evaluate(), 1)
+    }
+
+    #[test]
+    fn test_packet_evaluate_greater_than_operator() {
+        let packet = Decoder::parse("F600BC2D8F").unwrap();
+        assert_eq!(packet.evaluate(), 0)
+    }
+
+    #[test]
+    fn test_packet_evaluate_equal_to_operator() {
+        let packet = Decoder::parse("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(packet.evaluate(), 1)
+    }
+
+    #[test]
+    fn test_parse_invalid_hex_digit() {
+        let error = Decoder::parse("D2FG28").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert_eq!(error.text, "G");
+    }
+
+    #[test]
+    fn test_parse_truncated_transmission() {
+        let error = Decoder::parse("38006F4529").err().unwrap();
+        assert_eq!(error.text, "");
+    }
+
+    /// Encodes a transmission given as bits, padded with zeros to whole
+    /// hexadecimal digits.
+    fn hex(bits: &str) -> String {
+        let mut bits = String::from(bits);
+        while bits.len() % 4 != 0 {
+            bits.push('0');
+        }
+        (0..bits.len())
+            .step_by(4)
+            .map(|i| format!("{:X}", u8::from_str_radix(&bits[i..i + 4], 2).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_operator_without_sub_packets() {
+        let error = Decoder::parse("0A000").err().unwrap();
+        assert_eq!(
+            error.message,
+            "a sum, product, minimum or maximum packet needs sub-packets at bit 0"
+        );
+    }
+
+    #[test]
+    fn test_parse_comparison_with_one_sub_packet() {
+        // A greater than packet holding only the literal 1.
+        let error = Decoder::parse(&hex("00010110000000000100010000001"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.message,
+            "a comparison packet needs exactly two sub-packets at bit 0"
+        );
+    }
+
+    #[test]
+    fn test_parse_sub_packet_past_the_declared_length() {
+        // A sum packet declaring 10 bits of sub-packets, holding an 11 bit
+        // literal.
+        let error = Decoder::parse(&hex("000000000000000000101000010000001"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.message,
+            "sub-packet runs past the length of its operator packet at bit 22"
+        );
+    }
+
+    #[test]
+    fn test_parse_value_that_overflows() {
+        // The literal 2^32, as nine groups of four bits.
+        let literal = format!("00010010001{}00000", "10000".repeat(7));
+        // A sum of 2^32 and 2^32 fits, and a product does not.
+        let operator = |type_id| format!("000{}100000000010{}{}", type_id, literal, literal);
+        assert_eq!(
+            Decoder::parse(&hex(&operator("000"))).unwrap().evaluate(),
+            1 << 33
+        );
+        let error = Decoder::parse(&hex(&operator("001"))).err().unwrap();
+        assert_eq!(
+            error.message,
+            "the value of the packet does not fit in 64 bits at bit 0"
+        );
+    }
+
+    #[test]
+    fn test_parse_nesting_limit() {
+        // Sum packets of one sub-packet each, around the literal 1.
+        let nested = |depth: usize| hex(&("000000100000000001".repeat(depth) + "00010000001"));
+        assert_eq!(Decoder::parse(&nested(MAX_DEPTH)).unwrap().evaluate(), 1);
+        let error = Decoder::parse(&nested(MAX_DEPTH + 1)).err().unwrap();
+        assert_eq!(
+            error.message,
+            format!(
+                "operator packets are nested too deeply at bit {}",
+                MAX_DEPTH * 18 + 6
+            )
+        );
+        assert!(Decoder::parse(&nested(100_000)).is_err());
+    }
+
+    #[test]
+    fn test_inspect_packet() {
+        let packet = Decoder::parse("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(
+            Day16.inspect(&packet, "packet", &[]),
+            Ok(String::from(
+                "version 4, equal to
+  version 2, sum
+    version 2, literal 1
+    version 4, literal 3
+  version 6, product
+    version 0, literal 2
+    version 2, literal 2"
+            ))
+        );
+        assert_eq!(
+            Day16.inspect(&packet, "packet.evaluate", &["1"]),
+            Ok(String::from("4"))
+        );
+        assert_eq!(
+            Day16.inspect(&packet, "packet", &["0.1"]),
+            Ok(String::from("version 4, literal 3"))
+        );
+        assert!(Day16.inspect(&packet, "packet", &["0.2"]).is_err());
+    }
+
+    #[test]
+    fn test_trace_bit_offsets() {
+        let (_, lines) = trace::capture(trace::Level::Trace, || {
+            Decoder::parse("38006F45291200").unwrap()
+        });
+        assert_eq!(
+            lines,
+            [
+                "[debug y2021::day_16] bit 0: version 1, type 6",
+                "[debug y2021::day_16] bit 22: version 6, type 4",
+                "[trace y2021::day_16] bits 22..33: packet ends",
+                "[debug y2021::day_16] bit 33: version 2, type 4",
+                "[trace y2021::day_16] bits 33..49: packet ends",
+                "[trace y2021::day_16] bits 0..49: packet ends",
+            ]
+        );
+    }
+}
@@ -0,0 +1,31 @@
+//! Solutions to Advent of Code 2021.
+
+use crate::solution::DynSolution;
+
+pub const YEAR: u16 = 2021;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_16;
+
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_16::Day16,
+];